@@ -0,0 +1,141 @@
+use std::time::{Duration, Instant};
+
+use crate::{Error, Part, Solution, variants};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let count = sorted.len().max(1) as f64;
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / count;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / count;
+
+        let median = match sorted.len() {
+            0 => Duration::ZERO,
+            len if len % 2 == 1 => sorted[len / 2],
+            len => (sorted[len / 2 - 1] + sorted[len / 2]) / 2,
+        };
+
+        Stats {
+            min: sorted.first().copied().unwrap_or_default(),
+            median,
+            mean: Duration::from_nanos(mean as u64),
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Options {
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            warmup: 1,
+            runs: 10,
+        }
+    }
+}
+
+pub struct VariantBench {
+    pub part: Part,
+    pub variant: &'static str,
+    pub answer: String,
+    pub solve: Stats,
+}
+
+pub struct DayBench {
+    pub parse: Stats,
+    pub variants: Vec<VariantBench>,
+}
+
+fn measure<T>(
+    options: Options,
+    mut f: impl FnMut() -> Result<T, Error>,
+) -> Result<(T, Vec<Duration>), Error> {
+    for _ in 0..options.warmup {
+        f()?;
+    }
+
+    let mut samples = Vec::with_capacity(options.runs);
+    let mut last = None;
+    for _ in 0..options.runs.max(1) {
+        let start = Instant::now();
+        let value = f()?;
+        samples.push(start.elapsed());
+        last = Some(value);
+    }
+
+    // runs.max(1) above guarantees at least one value
+    Ok((last.unwrap(), samples))
+}
+
+// Times parsing and every variant of the requested parts `options.runs` times
+// each, after `options.warmup` untimed runs. A non-empty `names` only keeps
+// the variants named there.
+pub fn bench<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    names: &[String],
+    options: Options,
+) -> Result<DayBench, Error> {
+    let (parsed, parse_samples) = measure(options, || S::parse(input))?;
+
+    let variants = variants::<S>()
+        .into_iter()
+        .filter(|variant| parts.contains(&variant.part))
+        .filter(|variant| names.is_empty() || names.iter().any(|name| name == variant.name))
+        .map(|variant| {
+            let (answer, samples) = measure(options, || (variant.solve)(&parsed))?;
+
+            Ok(VariantBench {
+                part: variant.part,
+                variant: variant.name,
+                answer,
+                solve: Stats::from_samples(&samples),
+            })
+        })
+        .collect::<Result<_, Error>>()?;
+
+    Ok(DayBench {
+        parse: Stats::from_samples(&parse_samples),
+        variants,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_millis(v)).collect()
+    }
+
+    #[test]
+    fn computes_stats_for_odd_sample_count() {
+        let stats = Stats::from_samples(&ms(&[5, 1, 3]));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.stddev.as_micros(), 1632);
+    }
+
+    #[test]
+    fn averages_middle_samples_for_even_count() {
+        let stats = Stats::from_samples(&ms(&[4, 1, 2, 8]));
+        assert_eq!(stats.median, Duration::from_millis(3));
+    }
+}
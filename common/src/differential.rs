@@ -0,0 +1,208 @@
+use std::fmt::{self, Debug};
+
+use crate::rng::Rng;
+
+// Overrides the base seed, e.g. AOC_SEED=42 cargo test, to explore new inputs.
+pub const SEED_VAR: &str = "AOC_SEED";
+
+const DEFAULT_SEED: u64 = 2025;
+
+// Gives up shrinking after this many steps in case a shrinker never bottoms out.
+const MAX_SHRINKS: usize = 10_000;
+
+pub fn seed() -> u64 {
+    std::env::var(SEED_VAR)
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(DEFAULT_SEED)
+}
+
+// A generated input the implementations disagreed on, shrunk as far as it
+// would go while still failing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Failure<T> {
+    // `Rng::new(case_seed)` regenerates the original, unshrunk input
+    pub case_seed: u64,
+    pub shrinks: usize,
+    pub input: T,
+    pub message: String,
+}
+
+impl<T: Debug> fmt::Display for Failure<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "case seed {} failed: {}\nshrunk in {} steps to: {:?}",
+            self.case_seed, self.message, self.shrinks, self.input
+        )
+    }
+}
+
+// Runs `check` on `cases` inputs from `generate`. The first failing input is
+// shrunk by repeatedly taking the first candidate from `shrink` that still
+// fails. Returns how many cases passed.
+pub fn run<T: Clone>(
+    seed: u64,
+    cases: usize,
+    generate: impl Fn(&mut Rng) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    check: impl Fn(&T) -> Result<(), String>,
+) -> Result<usize, Failure<T>> {
+    let mut seeds = Rng::new(seed);
+
+    for _ in 0..cases {
+        let case_seed = seeds.next_u64();
+        let mut input = generate(&mut Rng::new(case_seed));
+        let Err(mut message) = check(&input) else {
+            continue;
+        };
+
+        let mut shrinks = 0;
+        while shrinks < MAX_SHRINKS {
+            let smaller = shrink(&input)
+                .into_iter()
+                .find_map(|candidate| check(&candidate).err().map(|msg| (candidate, msg)));
+            let Some((candidate, msg)) = smaller else {
+                break;
+            };
+            input = candidate;
+            message = msg;
+            shrinks += 1;
+        }
+
+        return Err(Failure {
+            case_seed,
+            shrinks,
+            input,
+            message,
+        });
+    }
+
+    Ok(cases)
+}
+
+// Ok if every implementation gave the same result, otherwise lists them all.
+pub fn all_equal<R: PartialEq + Debug>(results: &[(&str, R)]) -> Result<(), String> {
+    if results.windows(2).all(|pair| pair[0].1 == pair[1].1) {
+        return Ok(());
+    }
+
+    let listed: Vec<String> = results
+        .iter()
+        .map(|(name, result)| format!("{} = {:?}", name, result))
+        .collect();
+    Err(listed.join(", "))
+}
+
+// Smaller versions of a list: halves, then each item removed, then each item
+// replaced by its own smaller versions.
+pub fn shrink_vec<T: Clone>(items: &[T], shrink_item: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut candidates = Vec::new();
+    if items.len() > 1 {
+        let half = items.len() / 2;
+        candidates.push(items[..half].to_vec());
+        candidates.push(items[half..].to_vec());
+    }
+    for idx in 0..items.len() {
+        let mut removed = items.to_vec();
+        removed.remove(idx);
+        candidates.push(removed);
+    }
+    for (idx, item) in items.iter().enumerate() {
+        for smaller in shrink_item(item) {
+            let mut replaced = items.to_vec();
+            replaced[idx] = smaller;
+            candidates.push(replaced);
+        }
+    }
+
+    candidates
+}
+
+// Smaller numbers towards `min`: min itself, halfway there, one less.
+pub fn shrink_u64(n: u64, min: u64) -> Vec<u64> {
+    let mut candidates = vec![min, min + (n - min) / 2, n.saturating_sub(1)];
+    candidates.retain(|&c| c >= min && c < n);
+    candidates.dedup();
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generate(rng: &mut Rng) -> Vec<u64> {
+        (0..rng.range(0..=20)).map(|_| rng.range(0..=100)).collect()
+    }
+
+    fn shrink(items: &[u64]) -> Vec<Vec<u64>> {
+        shrink_vec(items, |&n| shrink_u64(n, 0))
+    }
+
+    #[test]
+    fn passes_when_implementations_agree() {
+        let passed = run(
+            1,
+            500,
+            generate,
+            |items| shrink(items),
+            |items| {
+                all_equal(&[
+                    ("iter", items.iter().sum::<u64>()),
+                    ("reversed", items.iter().rev().sum::<u64>()),
+                ])
+            },
+        );
+        assert_eq!(passed, Ok(500));
+    }
+
+    #[test]
+    fn shrinks_to_a_minimal_failure() {
+        // "buggy" ignores numbers above 50
+        let failure = run(
+            1,
+            500,
+            generate,
+            |items| shrink(items),
+            |items| {
+                all_equal(&[
+                    ("sum", items.iter().sum::<u64>()),
+                    ("buggy", items.iter().filter(|&&n| n <= 50).sum::<u64>()),
+                ])
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(failure.input, vec![51]);
+        assert_eq!(failure.message, "sum = 51, buggy = 0");
+        assert!(failure.to_string().contains("shrunk in"));
+    }
+
+    #[test]
+    fn case_seed_reproduces_the_input() {
+        let failure = run(
+            3,
+            100,
+            generate,
+            |_| Vec::new(),
+            |items| {
+                if items.len() > 10 {
+                    Err("long".to_string())
+                } else {
+                    Ok(())
+                }
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(failure.shrinks, 0);
+        assert_eq!(generate(&mut Rng::new(failure.case_seed)), failure.input);
+    }
+
+    #[test]
+    fn shrinks_numbers_towards_min() {
+        assert_eq!(shrink_u64(10, 0), vec![0, 5, 9]);
+        assert_eq!(shrink_u64(4, 3), vec![3]);
+        assert!(shrink_u64(3, 3).is_empty());
+    }
+}
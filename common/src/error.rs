@@ -0,0 +1,76 @@
+use std::fmt;
+
+// Points at the offending text of a malformed puzzle input.
+// Lines and columns are 1-based, like an editor shows them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl Error {
+    pub fn new(day: u8, line: usize, column: usize, text: &str, message: &str) -> Self {
+        Error {
+            day,
+            line,
+            column,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    // Works out the line and column from where `fragment` sits inside `input`,
+    // so `fragment` must be a slice of `input` (e.g. from `split` or `lines`).
+    pub fn locate(day: u8, input: &str, fragment: &str, message: &str) -> Self {
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(input.len());
+
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        let column = before[line_start..].chars().count() + 1;
+
+        Error::new(day, line, column, fragment, message)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "day{:02}:{}:{}: {} ({:?})",
+            self.day, self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_fragment_in_input() {
+        let input = "L68\nL30\nRx8";
+        let fragment = &input[9..];
+        let err = Error::locate(1, input, fragment, "not a number");
+
+        assert_eq!((err.line, err.column), (3, 2));
+        assert_eq!(err.to_string(), "day01:3:2: not a number (\"x8\")");
+    }
+
+    #[test]
+    fn locates_fragment_on_first_line() {
+        let input = "11-22,95-x";
+        let fragment = input.split(',').nth(1).unwrap();
+        let err = Error::locate(2, input, fragment, "invalid range");
+
+        assert_eq!((err.line, err.column), (1, 7));
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{Part, Solution, variants};
+
+// The contents of every plain ``` fence in a markdown file, in order. Fences
+// with a language (```rust) are code, not puzzle input, and are skipped.
+pub fn code_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut current: Option<(bool, Vec<&str>)> = None;
+
+    for line in markdown.lines() {
+        let Some(info) = line.trim_end().strip_prefix("```") else {
+            if let Some((_, lines)) = current.as_mut() {
+                lines.push(line);
+            }
+            continue;
+        };
+
+        match current.take() {
+            Some((true, lines)) => blocks.push(lines.join("\n")),
+            Some((false, _)) => {}
+            None => current = Some((info.is_empty(), Vec::new())),
+        }
+    }
+
+    blocks
+}
+
+// One `<block> <part> <answer>` line of a day's `examples.txt`: the expected
+// answer when the README's n-th (1-based) plain code block is the input.
+#[derive(Debug, PartialEq)]
+pub struct Example {
+    pub block: usize,
+    pub part: Part,
+    pub answer: String,
+}
+
+pub fn parse_annotations(content: &str) -> Result<Vec<Example>, String> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(idx, line)| {
+            let invalid = || format!("examples.txt:{}: invalid example: {}", idx + 1, line);
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [block, part, answer] = fields[..] else {
+                return Err(invalid());
+            };
+
+            Ok(Example {
+                block: block.parse().map_err(|_| invalid())?,
+                part: part
+                    .parse::<u8>()
+                    .ok()
+                    .and_then(Part::from_number)
+                    .ok_or_else(invalid)?,
+                answer: answer.to_string(),
+            })
+        })
+        .collect()
+}
+
+// Runs every variant of the annotated part against its README block and
+// returns how many answers were checked, or every mismatch.
+pub fn check<S: Solution>(readme: &str, annotations: &str) -> Result<usize, String> {
+    let blocks = code_blocks(readme);
+    let mut checked = 0;
+    let mut failures = Vec::new();
+
+    for example in parse_annotations(annotations)? {
+        let block = example
+            .block
+            .checked_sub(1)
+            .and_then(|idx| blocks.get(idx))
+            .ok_or(format!(
+                "README has {} code block(s), no block {}",
+                blocks.len(),
+                example.block
+            ))?;
+        let parsed = S::parse(block).map_err(|err| err.to_string())?;
+
+        for variant in variants::<S>().iter().filter(|v| v.part == example.part) {
+            let answer = (variant.solve)(&parsed).map_err(|err| err.to_string())?;
+            if answer != example.answer {
+                failures.push(format!(
+                    "block {} part {} ({}): expected {}, got {}",
+                    example.block,
+                    example.part.number(),
+                    variant.name,
+                    example.answer,
+                    answer
+                ));
+            }
+            checked += 1;
+        }
+
+        let references = S::references();
+        for reference in references.iter().filter(|r| r.part == example.part) {
+            let answer = match (reference.solve)(block) {
+                Ok(Some(answer)) => answer,
+                Ok(None) => continue,
+                Err(err) => {
+                    failures.push(format!(
+                        "block {} part {} (reference): {}",
+                        example.block,
+                        example.part.number(),
+                        err
+                    ));
+                    continue;
+                }
+            };
+            if answer != example.answer {
+                failures.push(format!(
+                    "block {} part {} (reference): expected {}, got {}",
+                    example.block,
+                    example.part.number(),
+                    example.answer,
+                    answer
+                ));
+            }
+            checked += 1;
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(checked)
+    } else {
+        Err(failures.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::Sum;
+
+    const README: &str = "# Day 0: Sums
+
+For example:
+
+```
+1
+2
+3
+```
+
+```rust
+let ignored = true;
+```
+
+Or:
+
+```
+10
+```
+";
+
+    #[test]
+    fn extracts_plain_code_blocks() {
+        assert_eq!(code_blocks(README), ["1\n2\n3", "10"]);
+    }
+
+    #[test]
+    fn checks_annotated_answers() {
+        assert_eq!(
+            check::<Sum>(README, "# block part answer\n1 1 6\n2 1 10\n"),
+            Ok(2)
+        );
+
+        let err = check::<Sum>(README, "1 1 7").unwrap_err();
+        assert_eq!(err, "block 1 part 1 (default): expected 7, got 6");
+        assert!(check::<Sum>(README, "3 1 6").is_err());
+        assert!(check::<Sum>(README, "1 one 6").is_err());
+    }
+
+    #[test]
+    fn checks_references_that_can_answer() {
+        // block 1 is too big for the reference, so only the default counts
+        assert_eq!(check::<Sum>(README, "1 2 3"), Ok(1));
+        assert_eq!(check::<Sum>(README, "2 2 1"), Ok(2));
+    }
+}
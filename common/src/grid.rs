@@ -0,0 +1,284 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::Error;
+
+// (row, col), counted from the top left.
+pub type Pos = (usize, usize);
+
+pub const UP: (isize, isize) = (-1, 0);
+pub const DOWN: (isize, isize) = (1, 0);
+pub const LEFT: (isize, isize) = (0, -1);
+pub const RIGHT: (isize, isize) = (0, 1);
+
+const ORTHOGONAL: [(isize, isize); 4] = [UP, LEFT, RIGHT, DOWN];
+const ALL_AROUND: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+// A rectangle of cells stored row after row in one Vec, so every lookup is
+// bounds checked in one place instead of in each day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    // `cells` row after row; panics unless they fill whole rows of `width`.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Grid<T> {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "cells don't fill the rows"
+        );
+        let height = cells.len() / width;
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // None if there are no rows or they aren't all the same non-zero width.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let width = rows.first()?.len();
+        if width == 0 || rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Grid::from_vec(width, rows.into_iter().flatten().collect()))
+    }
+
+    // One row per line, each character turned into a cell by `cell`, which
+    // returns the error message for characters it doesn't accept.
+    pub fn parse(
+        day: u8,
+        input: &str,
+        cell: impl Fn(char) -> Result<T, &'static str>,
+    ) -> Result<Grid<T>, Error> {
+        let Some(width) = input.lines().next().map(|line| line.chars().count()) else {
+            return Err(Error::locate(day, input, input, "empty grid"));
+        };
+
+        let mut cells = Vec::with_capacity(input.len());
+        for line in input.lines() {
+            for (idx, c) in line.char_indices() {
+                let value = cell(c).map_err(|message| {
+                    Error::locate(day, input, &line[idx..idx + c.len_utf8()], message)
+                })?;
+                cells.push(value);
+            }
+            if line.chars().count() != width {
+                return Err(Error::locate(
+                    day,
+                    input,
+                    line,
+                    "rows must all be the same width",
+                ));
+            }
+        }
+        if width == 0 {
+            return Err(Error::locate(day, input, input, "empty grid"));
+        }
+
+        Ok(Grid::from_vec(width, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, col): Pos) -> Option<&T> {
+        if col < self.width {
+            self.cells.get(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (row, col): Pos) -> Option<&mut T> {
+        if col < self.width {
+            self.cells.get_mut(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    // The position one step away in `direction`, if it's still on the grid.
+    pub fn step(&self, (row, col): Pos, (dr, dc): (isize, isize)) -> Option<Pos> {
+        // stepping off the top or left wraps around to a huge index
+        let (row, col) = (row.wrapping_add_signed(dr), col.wrapping_add_signed(dc));
+        (row < self.height && col < self.width).then_some((row, col))
+    }
+
+    // Up, left, right and down, leaving out those off the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&dir| self.step(pos, dir))
+    }
+
+    // The orthogonal and diagonal neighbours that are on the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ALL_AROUND
+            .iter()
+            .filter_map(move |&dir| self.step(pos, dir))
+    }
+
+    // Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |idx| (idx / width, idx % width))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    // Panics past the last column, like `row` does past the last row, rather
+    // than walking into the cells of later rows.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            col < self.width,
+            "column {} out of bounds (width {})",
+            col,
+            self.width
+        );
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    // The first position holding `value`, reading row by row.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        let idx = self.cells.iter().position(|cell| cell == value)?;
+        Some((idx / self.width, idx % self.width))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid::from_vec(self.width, self.cells.iter().map(f).collect())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).expect("position outside the grid")
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos).expect("position outside the grid")
+    }
+}
+
+// Rows on their own lines, no trailing newline.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, Error> {
+        Grid::parse(0, input, |c| c.to_digit(10).ok_or("expected a digit"))
+    }
+
+    #[test]
+    fn parses_rows_of_cells() {
+        let grid = digits("123\n456").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+    }
+
+    #[test]
+    fn reports_bad_cells_and_ragged_rows() {
+        let err = digits("123\n4x6").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+
+        let err = digits("123\n45").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.message.as_str()),
+            (2, 1, "rows must all be the same width")
+        );
+
+        assert!(digits("").is_err());
+        assert!(Grid::from_rows(vec![vec![1], vec![2, 3]]).is_none());
+    }
+
+    #[test]
+    fn neighbours_stay_on_the_grid() {
+        let grid = digits("123\n456\n789").unwrap();
+        let values =
+            |positions: Vec<Pos>| positions.iter().map(|&pos| grid[pos]).collect::<Vec<_>>();
+
+        assert_eq!(values(grid.neighbours4((0, 0)).collect()), [2, 4]);
+        assert_eq!(values(grid.neighbours4((1, 1)).collect()), [2, 4, 6, 8]);
+        assert_eq!(values(grid.neighbours8((0, 2)).collect()), [2, 5, 6]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.step((0, 0), LEFT), None);
+        assert_eq!(grid.step((2, 2), DOWN), None);
+    }
+
+    #[test]
+    fn iterates_rows_columns_and_positions() {
+        let grid = digits("12\n34\n56").unwrap();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2], [3, 4], [5, 6]]);
+        let columns: Vec<Vec<u32>> = grid.columns().map(|col| col.copied().collect()).collect();
+        assert_eq!(columns, [[1, 3, 5], [2, 4, 6]]);
+        assert_eq!(grid.positions().nth(3), Some((1, 1)));
+        assert_eq!(grid.find(&4), Some((1, 1)));
+        assert_eq!(grid.find(&9), None);
+        assert_eq!(grid.map(|n| n * 10)[(2, 0)], 50);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 2 out of bounds")]
+    fn rejects_columns_past_the_width() {
+        digits("12\n34").unwrap().column(2).count();
+    }
+}
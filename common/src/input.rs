@@ -0,0 +1,156 @@
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+const EXAMPLE_SUFFIX: &str = "_example_input.txt";
+
+// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    // dayNN/input.txt
+    Default,
+    // `-` on the command line
+    Stdin,
+    File(PathBuf),
+    // dayNN/<name>_example_input.txt, e.g. day01's `part1`
+    Example(String),
+}
+
+impl Source {
+    pub fn from_arg(arg: Option<&str>) -> Source {
+        match arg {
+            None => Source::Default,
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            Source::Default => Some(day_dir(day).join("input.txt")),
+            Source::Stdin => None,
+            Source::File(path) => Some(path.clone()),
+            Source::Example(name) => Some(day_dir(day).join(format!("{}{}", name, EXAMPLE_SUFFIX))),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Io(String, io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(f, "Input file not found: {}", path.display()),
+            InputError::Io(name, err) => write!(f, "Failed to read {}: {}", name, err),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+pub fn day_dir(day: u8) -> PathBuf {
+    PathBuf::from(format!("day{:02}", day))
+}
+
+// The names of every `<name>_example_input.txt` in `dir`, sorted, ready for
+// `Source::Example`. A missing directory just has no examples.
+pub fn example_names(dir: &Path) -> io::Result<Vec<String>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    let mut names = Vec::new();
+    for entry in entries {
+        let file_name = entry?.file_name();
+        if let Some(name) = file_name.to_string_lossy().strip_suffix(EXAMPLE_SUFFIX) {
+            names.push(name.to_string());
+        }
+    }
+    names.sort();
+
+    Ok(names)
+}
+
+pub fn load(day: u8, source: &Source) -> Result<String, InputError> {
+    let Some(path) = source.path(day) else {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|err| InputError::Io("stdin".to_string(), err))?;
+        return Ok(input);
+    };
+
+    std::fs::read_to_string(&path).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound(path.clone()),
+        _ => InputError::Io(path.display().to_string(), err),
+    })
+}
+
+// Loads the input named by the first command line argument, as the day
+// binaries take it: a path, `-` for stdin, or nothing for dayNN/input.txt.
+pub fn load_from_args(day: u8) -> Result<String, InputError> {
+    let arg = std::env::args().nth(1);
+    load(day, &Source::from_arg(arg.as_deref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_paths_inside_the_day_directory() {
+        assert_eq!(
+            Source::Default.path(7),
+            Some(PathBuf::from("day07/input.txt"))
+        );
+        assert_eq!(
+            Source::Example("part1".to_string()).path(1),
+            Some(PathBuf::from("day01/part1_example_input.txt"))
+        );
+        assert_eq!(Source::from_arg(Some("-")).path(1), None);
+    }
+
+    #[test]
+    fn discovers_example_inputs() {
+        let dir = std::env::temp_dir().join(format!("aoc2025_examples_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for file in [
+            "part2_example_input.txt",
+            "part1_example_input.txt",
+            "input.txt",
+        ] {
+            std::fs::write(dir.join(file), "").unwrap();
+        }
+
+        let names = example_names(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(names, ["part1", "part2"]);
+        assert!(example_names(&dir).unwrap().is_empty());
+    }
+
+    #[test]
+    fn reports_missing_files() {
+        let source = Source::File(PathBuf::from("does/not/exist.txt"));
+        let err = load(1, &source).unwrap_err();
+        assert!(matches!(err, InputError::NotFound(_)));
+        assert_eq!(err.to_string(), "Input file not found: does/not/exist.txt");
+    }
+
+    #[test]
+    fn reads_existing_files() {
+        let path = std::env::temp_dir().join("aoc2025_input_test.txt");
+        std::fs::write(&path, "L68\nR48\n").unwrap();
+
+        let input = load(1, &Source::File(path.clone())).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        assert_eq!(input, "L68\nR48\n");
+    }
+}
@@ -0,0 +1,288 @@
+pub mod bench;
+pub mod differential;
+pub mod error;
+pub mod examples;
+pub mod grid;
+pub mod input;
+pub mod pool;
+pub mod rng;
+
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+pub use error::Error;
+use rng::Rng;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u8) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+// Every day implements this so the runner can treat them all the same way:
+// parse the raw input once, then answer each part from the parsed value.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, Error>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, Error>;
+
+    // Alternative implementations (threaded, serial...) of either part,
+    // benchmarked next to `part1`/`part2`.
+    fn variants() -> Vec<Variant<Self::Input>> {
+        Vec::new()
+    }
+
+    // A random but valid puzzle input from `rng`, `size` scaling it up the
+    // way the day's input grows (more lines, a bigger grid...). Used to
+    // stress-test and benchmark beyond input.txt.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+
+    // Deliberately naive solvers to cross-check the real ones, usually only
+    // compiled with the day's `reference` feature.
+    fn references() -> Vec<Reference> {
+        Vec::new()
+    }
+}
+
+pub const DEFAULT_VARIANT: &str = "default";
+
+pub struct Variant<I> {
+    pub name: &'static str,
+    pub part: Part,
+    pub solve: fn(&I) -> Result<String, Error>,
+}
+
+// A deliberately naive solver for one part, to cross-check the real one. It
+// gets the raw input, but most days reuse their parser, so it checks the
+// solving rather than the parsing.
+pub struct Reference {
+    pub part: Part,
+    // Ok(None) when the input is too big to brute-force, an error when the
+    // reference can't read it.
+    pub solve: fn(&str) -> Result<Option<String>, Error>,
+}
+
+// Each reference's part and answer, Ok(None) where it was skipped.
+pub type ReferenceAnswers = Vec<(Part, Result<Option<String>, Error>)>;
+
+pub fn run_references<S: Solution>(input: &str) -> ReferenceAnswers {
+    S::references()
+        .iter()
+        .map(|reference| (reference.part, (reference.solve)(input)))
+        .collect()
+}
+
+// `part1` and `part2` as the "default" variants, followed by the day's own.
+pub fn variants<S: Solution>() -> Vec<Variant<S::Input>> {
+    let mut variants = vec![
+        Variant {
+            name: DEFAULT_VARIANT,
+            part: Part::One,
+            solve: |input| Ok(S::part1(input)?.to_string()),
+        },
+        Variant {
+            name: DEFAULT_VARIANT,
+            part: Part::Two,
+            solve: |input| Ok(S::part2(input)?.to_string()),
+        },
+    ];
+    variants.extend(S::variants());
+    variants
+}
+
+pub struct PartRun {
+    pub part: Part,
+    pub answer: String,
+    pub solve: Duration,
+}
+
+pub struct DayRun {
+    // loading the input happens before `run`, so whoever read it fills this in
+    pub read: Duration,
+    pub parse: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+// Parses the input once and solves the requested parts, timing each step.
+pub fn run<S: Solution>(input: &str, parts: &[Part]) -> Result<DayRun, Error> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(&parsed)?.to_string(),
+                Part::Two => S::part2(&parsed)?.to_string(),
+            };
+
+            Ok(PartRun {
+                part,
+                answer,
+                solve: start.elapsed(),
+            })
+        })
+        .collect::<Result<_, Error>>()?;
+
+    Ok(DayRun {
+        read: Duration::ZERO,
+        parse,
+        parts,
+    })
+}
+
+// A step of solving a day that `run_phases` hands to its probe.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Phase {
+    Parse,
+    Solve(Part, &'static str),
+}
+
+// Called with each phase and a closure that performs it, so the caller can
+// measure around the work (allocations, for instance) without knowing `S`.
+pub type Probe<'a> =
+    dyn FnMut(Phase, &mut dyn FnMut() -> Result<(), Error>) -> Result<(), Error> + 'a;
+
+// Parses once, then runs every variant of the requested parts, each inside
+// `probe`.
+pub fn run_phases<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    probe: &mut Probe,
+) -> Result<(), Error> {
+    let mut parsed = None;
+    probe(Phase::Parse, &mut || {
+        parsed = Some(S::parse(input)?);
+        Ok(())
+    })?;
+    // a probe that skips the closure leaves nothing to solve
+    let Some(parsed) = parsed else {
+        return Ok(());
+    };
+
+    for variant in variants::<S>().iter().filter(|v| parts.contains(&v.part)) {
+        probe(Phase::Solve(variant.part, variant.name), &mut || {
+            (variant.solve)(&parsed).map(|_| ())
+        })?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+
+        type Input = Vec<i64>;
+        type Answer1 = i64;
+        type Answer2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, Error> {
+            input
+                .lines()
+                .map(|line| {
+                    line.parse()
+                        .map_err(|_| Error::locate(0, input, line, "not a number"))
+                })
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Answer1, Error> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Self::Answer2, Error> {
+            Ok(input.len())
+        }
+
+        // "too big" past two lines, to exercise skipping
+        fn references() -> Vec<Reference> {
+            vec![Reference {
+                part: Part::Two,
+                solve: |input| {
+                    let count = Sum::parse(input)?.len();
+                    Ok((count <= 2).then(|| count.to_string()))
+                },
+            }]
+        }
+    }
+
+    #[test]
+    fn solves_both_parts() {
+        let run = run::<Sum>("1\n2\n3", &[Part::One, Part::Two]).unwrap();
+        let answers: Vec<_> = run.parts.iter().map(|part| part.answer.as_str()).collect();
+        assert_eq!(answers, ["6", "3"]);
+    }
+
+    #[test]
+    fn solves_only_requested_parts() {
+        let run = run::<Sum>("1\n2\n3", &[Part::Two]).unwrap();
+        assert_eq!(run.parts.len(), 1);
+        assert_eq!(run.parts[0].part, Part::Two);
+    }
+
+    #[test]
+    fn probes_every_phase() {
+        let mut phases = Vec::new();
+        run_phases::<Sum>("1\n2", &[Part::Two], &mut |phase, work| {
+            phases.push(phase);
+            work()
+        })
+        .unwrap();
+
+        assert_eq!(
+            phases,
+            [Phase::Parse, Phase::Solve(Part::Two, DEFAULT_VARIANT)]
+        );
+    }
+
+    #[test]
+    fn runs_references_unless_too_big() {
+        assert_eq!(
+            run_references::<Sum>("1\n2"),
+            [(Part::Two, Ok(Some("2".to_string())))]
+        );
+        assert_eq!(run_references::<Sum>("1\n2\n3"), [(Part::Two, Ok(None))]);
+        assert!(run_references::<Sum>("1\nx")[0].1.is_err());
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        let err = run::<Sum>("1\nx\n3", &Part::ALL).err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
@@ -0,0 +1,138 @@
+use std::ops::Range;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+// Overrides the worker count of the shared pool, e.g. AOC_WORKERS=1.
+pub const WORKERS_VAR: &str = "AOC_WORKERS";
+
+// Jobs handed out per worker. More jobs than workers keeps everyone busy when
+// some jobs turn out slower than others.
+const JOBS_PER_WORKER: usize = 4;
+
+// A fixed number of workers that pull jobs off a shared counter until none
+// are left. Workers are scoped threads that only live for one `map`, so jobs
+// can borrow from the caller instead of being cloned into an `Arc`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pool {
+    workers: usize,
+}
+
+impl Pool {
+    pub fn new(workers: usize) -> Pool {
+        Pool {
+            workers: workers.max(1),
+        }
+    }
+
+    // The pool the parallel variants use: AOC_WORKERS if set, otherwise one
+    // worker per available core.
+    pub fn shared() -> &'static Pool {
+        static SHARED: OnceLock<Pool> = OnceLock::new();
+        SHARED.get_or_init(|| {
+            let from_env = std::env::var(WORKERS_VAR)
+                .ok()
+                .and_then(|value| value.trim().parse().ok());
+            let cores = thread::available_parallelism().map_or(1, |n| n.get());
+            Pool::new(from_env.unwrap_or(cores))
+        })
+    }
+
+    pub fn workers(&self) -> usize {
+        self.workers
+    }
+
+    // How many pieces to split work into so it balances across the workers.
+    pub fn jobs(&self) -> usize {
+        self.workers * JOBS_PER_WORKER
+    }
+
+    // Runs `work` on every job and returns the results in job order.
+    pub fn map<T, R, F>(&self, jobs: &[T], work: F) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> R + Sync,
+    {
+        let workers = self.workers.min(jobs.len());
+        if workers <= 1 {
+            return jobs.iter().map(work).collect();
+        }
+
+        let next = AtomicUsize::new(0);
+        let mut results: Vec<(usize, R)> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut done = Vec::new();
+                        loop {
+                            let idx = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(job) = jobs.get(idx) else {
+                                break done;
+                            };
+                            done.push((idx, work(job)));
+                        }
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|h| h.join().unwrap())
+                .collect()
+        });
+
+        results.sort_by_key(|(idx, _)| *idx);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+}
+
+// Splits 0..len into at most `pieces` contiguous ranges whose lengths differ
+// by at most one.
+pub fn balanced(len: usize, pieces: usize) -> Vec<Range<usize>> {
+    let pieces = pieces.clamp(1, len.max(1));
+    let (size, extra) = (len / pieces, len % pieces);
+
+    let mut start = 0;
+    (0..pieces)
+        .map(|piece| {
+            let end = start + size + usize::from(piece < extra);
+            let range = start..end;
+            start = end;
+            range
+        })
+        .filter(|range| !range.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn balances_ranges() {
+        assert_eq!(balanced(10, 3), vec![0..4, 4..7, 7..10]);
+        assert_eq!(balanced(2, 8), vec![0..1, 1..2]);
+        assert_eq!(balanced(5, 0), vec![0..5]);
+        assert!(balanced(0, 4).is_empty());
+    }
+
+    #[test]
+    fn maps_jobs_in_order() {
+        let jobs: Vec<u64> = (0..100).collect();
+        for workers in [0, 1, 3, 16] {
+            let squares = Pool::new(workers).map(&jobs, |n| n * n);
+            assert_eq!(squares, jobs.iter().map(|n| n * n).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn borrows_from_the_caller() {
+        let rows = [vec![1, 2], vec![3, 4], vec![5, 6]];
+        let pool = Pool::new(2);
+        let sums = pool.map(&balanced(rows.len(), pool.jobs()), |range| {
+            rows[range.clone()].iter().flatten().sum::<i32>()
+        });
+        assert_eq!(sums.iter().sum::<i32>(), 21);
+    }
+}
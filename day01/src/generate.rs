@@ -0,0 +1,25 @@
+use common::rng::Rng;
+
+// `size` rotations like R17 or L999.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let direction = if rng.chance(50) { 'L' } else { 'R' };
+            format!("{}{}", direction, rng.range(1..=999))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_rotations;
+
+    #[test]
+    fn generates_valid_rotations() {
+        let input = input(&mut Rng::new(1), 500);
+        assert_eq!(parse_rotations(&input).unwrap().len(), 500);
+        assert_eq!(input, super::input(&mut Rng::new(1), 500));
+    }
+}
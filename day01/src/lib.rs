@@ -0,0 +1,161 @@
+use common::rng::Rng;
+use common::{Error, Solution};
+
+pub mod generate;
+#[cfg(feature = "reference")]
+pub mod reference;
+
+pub enum Method {
+    Default,
+    Click, // 0x434C49434B
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+pub fn solve(rotations: &[(Direction, i32)], method: Method) -> Result<i32, Error> {
+    match method {
+        Method::Default => {
+            let mut pointer = 50;
+            let mut zero_count = 0;
+
+            for &(direction, amount) in rotations {
+                match direction {
+                    Direction::Right => pointer += amount,
+                    Direction::Left => pointer -= amount,
+                }
+
+                pointer = pointer.rem_euclid(100);
+
+                if pointer == 0 {
+                    zero_count += 1;
+                }
+            }
+
+            Ok(zero_count)
+        }
+        Method::Click => {
+            let mut pointer = 50;
+            let mut zero_passes = 0;
+
+            for &(direction, amount) in rotations {
+                match direction {
+                    Direction::Right => {
+                        pointer += amount;
+                        zero_passes += pointer / 100;
+                    }
+                    Direction::Left => {
+                        let start = pointer;
+                        pointer -= amount;
+                        if start > 0 && amount >= start {
+                            zero_passes += 1 + (amount - start) / 100;
+                        } else if start == 0 {
+                            zero_passes += amount / 100;
+                        }
+                    }
+                }
+
+                pointer = pointer.rem_euclid(100);
+            }
+
+            Ok(zero_passes)
+        }
+    }
+}
+
+fn parse_line(input: &str, line: &str) -> Result<(Direction, i32), Error> {
+    // split direction and distance
+    let direction = match line.chars().next() {
+        Some('R') => Direction::Right,
+        Some('L') => Direction::Left,
+        _ => return Err(Error::locate(1, input, line, "expected L or R")),
+    };
+    let amount = &line[1..];
+    let amount = amount
+        .parse::<i32>()
+        .map_err(|_| Error::locate(1, input, amount, "not a number"))?;
+
+    Ok((direction, amount))
+}
+
+pub fn parse_rotations(input: &str) -> Result<Vec<(Direction, i32)>, Error> {
+    input.lines().map(|line| parse_line(input, line)).collect()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<(Direction, i32)>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_rotations(input.trim_end())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, Error> {
+        solve(input, Method::Default)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, Error> {
+        solve(input, Method::Click)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    #[cfg(feature = "reference")]
+    fn references() -> Vec<common::Reference> {
+        reference::references()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part1() {
+        let test_input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+        let rotations = parse_rotations(test_input).unwrap();
+        assert_eq!(solve(&rotations, Method::Default), Ok(3));
+    }
+
+    #[test]
+    fn test_part2() {
+        let test_input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+        let rotations = parse_rotations(test_input).unwrap();
+        assert_eq!(solve(&rotations, Method::Click), Ok(6));
+    }
+
+    #[test]
+    fn readme_examples() {
+        let checked = common::examples::check::<Day01>(
+            include_str!("../README.md"),
+            include_str!("../examples.txt"),
+        );
+        assert!(matches!(checked, Ok(count) if count >= 2), "{:?}", checked);
+    }
+
+    #[test]
+    fn solution_trait() {
+        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+        let parsed = Day01::parse(input).unwrap();
+        assert_eq!(Day01::part1(&parsed), Ok(3));
+        assert_eq!(Day01::part2(&parsed), Ok(6));
+    }
+
+    #[test]
+    fn reports_malformed_lines() {
+        let err = parse_rotations("L68\nX30").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "X30"));
+
+        let err = parse_rotations("L68\nR3o").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "3o"));
+    }
+}
@@ -0,0 +1,94 @@
+use common::{Error, Part, Reference};
+
+use crate::{Direction, parse_rotations};
+
+const MAX_CLICKS: i64 = 100_000_000;
+
+// Turns the dial one click at a time instead of working out the passes over 0
+// with division. Returns the zeros landed on after each rotation (part 1) and
+// after each click (part 2).
+fn simulate(input: &str) -> Result<Option<(i64, i64)>, Error> {
+    let rotations = parse_rotations(input.trim_end())?;
+    let clicks: i64 = rotations.iter().map(|&(_, amount)| amount as i64).sum();
+    if clicks > MAX_CLICKS {
+        return Ok(None);
+    }
+
+    let mut dial = 50;
+    let (mut rotation_zeros, mut click_zeros) = (0, 0);
+    for (direction, amount) in rotations {
+        for _ in 0..amount {
+            dial = match direction {
+                Direction::Right => (dial + 1) % 100,
+                Direction::Left => (dial + 99) % 100,
+            };
+            if dial == 0 {
+                click_zeros += 1;
+            }
+        }
+        if dial == 0 {
+            rotation_zeros += 1;
+        }
+    }
+
+    Ok(Some((rotation_zeros, click_zeros)))
+}
+
+pub fn references() -> Vec<Reference> {
+    vec![
+        Reference {
+            part: Part::One,
+            solve: |input| Ok(simulate(input)?.map(|(zeros, _)| zeros.to_string())),
+        },
+        Reference {
+            part: Part::Two,
+            solve: |input| Ok(simulate(input)?.map(|(_, zeros)| zeros.to_string())),
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Method, solve};
+    use common::differential;
+
+    #[test]
+    fn clicks_match_division() {
+        let checked = differential::run(
+            differential::seed(),
+            2000,
+            |rng| {
+                let size = rng.range(1..=20) as usize;
+                crate::generate::input(rng, size)
+            },
+            |input| {
+                let lines: Vec<&str> = input.lines().collect();
+                differential::shrink_vec(&lines, |_| Vec::new())
+                    .iter()
+                    .map(|lines| lines.join("\n"))
+                    .collect()
+            },
+            |input| {
+                let rotations = parse_rotations(input).unwrap();
+                differential::all_equal(&[
+                    (
+                        "division",
+                        Some((
+                            solve(&rotations, Method::Default).unwrap() as i64,
+                            solve(&rotations, Method::Click).unwrap() as i64,
+                        )),
+                    ),
+                    ("clicks", simulate(input).unwrap()),
+                ])
+            },
+        );
+        assert!(checked.is_ok(), "{}", checked.unwrap_err());
+    }
+
+    #[test]
+    fn lands_on_zero_from_both_sides() {
+        assert_eq!(simulate("L50\nR100\nL200"), Ok(Some((3, 4))));
+        assert!(simulate("L50\nX3").is_err());
+    }
+}
@@ -0,0 +1,45 @@
+use common::rng::Rng;
+
+// `size` non-overlapping id ranges of 1 to 10 digit ids, in random order.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut ranges: Vec<(u64, u64)> = (0..size)
+        .map(|_| {
+            let digits = rng.range(1..=10) as u32;
+            let start = rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1);
+            (start, start + rng.range(0..=100_000))
+        })
+        .collect();
+
+    // push overlapping ranges past the previous one
+    ranges.sort();
+    for idx in 1..ranges.len() {
+        let previous_end = ranges[idx - 1].1;
+        let (start, end) = ranges[idx];
+        if start <= previous_end {
+            ranges[idx] = (previous_end + 2, previous_end + 2 + (end - start));
+        }
+    }
+    rng.shuffle(&mut ranges);
+
+    ranges
+        .iter()
+        .map(|(start, end)| format!("{}-{}", start, end))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_id_ranges;
+
+    #[test]
+    fn generates_disjoint_ranges() {
+        let mut ranges = parse_id_ranges(&input(&mut Rng::new(1), 200)).unwrap();
+        assert_eq!(ranges.len(), 200);
+
+        ranges.sort();
+        assert!(ranges.windows(2).all(|pair| pair[0].1 < pair[1].0));
+        assert!(ranges.iter().all(|(start, end)| start <= end));
+    }
+}
@@ -0,0 +1,291 @@
+use common::pool::Pool;
+use common::rng::Rng;
+use common::{Error, Part, Solution, Variant};
+
+pub mod generate;
+
+fn parse_id_range(input: &str, id_range: &str) -> Result<(u64, u64), Error> {
+    let Some((start, end)) = id_range.split_once('-') else {
+        return Err(Error::locate(
+            2,
+            input,
+            id_range,
+            "expected a range like 11-22",
+        ));
+    };
+    let parse_id = |id: &str| {
+        id.trim()
+            .parse::<u64>()
+            .map_err(|_| Error::locate(2, input, id, "invalid id"))
+    };
+
+    Ok((parse_id(start)?, parse_id(end)?))
+}
+
+pub fn parse_id_ranges(input: &str) -> Result<Vec<(u64, u64)>, Error> {
+    input
+        .split(',')
+        .map(|id_range| parse_id_range(input, id_range))
+        .collect()
+}
+
+pub fn sum_all_invalid_ids_part1(ranges: &[(u64, u64)]) -> u64 {
+    ranges
+        .iter()
+        .map(|&(start, end)| {
+            (start..=end)
+                .filter(|id| {
+                    let id_str = id.to_string();
+                    id_str.len() % 2 == 0
+                        && id_str[..id_str.len() / 2] == id_str[id_str.len() / 2..]
+                })
+                .sum::<u64>()
+        })
+        .sum()
+}
+
+pub fn sum_all_invalid_ids_part2(ranges: &[(u64, u64)]) -> u64 {
+    ranges
+        .iter()
+        .map(|&(start, end)| (start..=end).filter(is_repeated_pattern).sum::<u64>())
+        .sum()
+}
+
+// Cuts the ranges into pieces of roughly equal size, so one huge range
+// doesn't leave every other worker idle. Reversed ranges like 22-11 are empty,
+// as they are for the serial solvers, and the math saturates near u64::MAX.
+fn split_ranges(ranges: &[(u64, u64)], pieces: usize) -> Vec<(u64, u64)> {
+    let non_empty = || ranges.iter().filter(|&&(start, end)| start <= end);
+    let total = non_empty()
+        .map(|&(start, end)| (end - start).saturating_add(1))
+        .fold(0u64, u64::saturating_add);
+    let max_len = total.div_ceil(pieces.max(1) as u64).max(1);
+
+    non_empty()
+        .flat_map(|&(start, end)| {
+            let mut next = Some(start);
+            std::iter::from_fn(move || {
+                let from = next?;
+                let to = end.min(from.saturating_add(max_len - 1));
+                next = to.checked_add(1).filter(|&from| from <= end);
+                Some((from, to))
+            })
+        })
+        .collect()
+}
+
+fn sum_on_pool(ranges: &[(u64, u64)], is_invalid: fn(&u64) -> bool) -> u64 {
+    let pool = Pool::shared();
+    pool.map(&split_ranges(ranges, pool.jobs()), |&(start, end)| {
+        (start..=end).filter(is_invalid).sum::<u64>()
+    })
+    .into_iter()
+    .sum()
+}
+
+pub fn sum_all_invalid_ids_threaded_part1(ranges: &[(u64, u64)]) -> u64 {
+    sum_on_pool(ranges, |id| {
+        let s = id.to_string();
+        s.len() % 2 == 0 && s[..s.len() / 2] == s[s.len() / 2..]
+    })
+}
+
+pub fn sum_all_invalid_ids_threaded_part2(ranges: &[(u64, u64)]) -> u64 {
+    sum_on_pool(ranges, is_repeated_pattern)
+}
+
+fn is_repeated_pattern(n: &u64) -> bool {
+    let s = n.to_string();
+
+    (1..=s.len() / 2)
+        .find(|&pattern_len| {
+            if !s.len().is_multiple_of(pattern_len) {
+                return false;
+            }
+            let pattern = &s[..pattern_len];
+
+            s.as_bytes()
+                .chunks(pattern_len)
+                .all(|chunk| chunk == pattern.as_bytes())
+        })
+        .is_some()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<(u64, u64)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_id_ranges(input.trim_end())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, Error> {
+        Ok(sum_all_invalid_ids_part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, Error> {
+        Ok(sum_all_invalid_ids_part2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant {
+                name: "threaded",
+                part: Part::One,
+                solve: |input| Ok(sum_all_invalid_ids_threaded_part1(input).to_string()),
+            },
+            Variant {
+                name: "threaded",
+                part: Part::Two,
+                solve: |input| Ok(sum_all_invalid_ids_threaded_part2(input).to_string()),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::differential;
+    use common::rng::Rng;
+
+    #[test]
+    fn it_works_serial_part1() {
+        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+        let ranges = parse_id_ranges(input).unwrap();
+        assert_eq!(sum_all_invalid_ids_part1(&ranges), 1227775554);
+    }
+
+    #[test]
+    fn it_works_threaded_part1() {
+        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+        let ranges = parse_id_ranges(input).unwrap();
+        assert_eq!(sum_all_invalid_ids_threaded_part1(&ranges), 1227775554);
+    }
+
+    // 4174379265 is the part 2 answer for the puzzle's example; the test
+    // used to expect 43287141963 (the real input's answer) and failed
+    #[test]
+    fn it_works_serial_part2() {
+        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+        let ranges = parse_id_ranges(input).unwrap();
+        assert_eq!(sum_all_invalid_ids_part2(&ranges), 4174379265);
+    }
+
+    #[test]
+    fn it_works_threaded_part2() {
+        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+        let ranges = parse_id_ranges(input).unwrap();
+        assert_eq!(sum_all_invalid_ids_threaded_part2(&ranges), 4174379265);
+    }
+
+    #[test]
+    fn splits_ranges_evenly() {
+        assert_eq!(
+            split_ranges(&[(1, 10), (20, 21)], 3),
+            vec![(1, 4), (5, 8), (9, 10), (20, 21)]
+        );
+        assert_eq!(split_ranges(&[(5, 5)], 8), vec![(5, 5)]);
+        assert_eq!(split_ranges(&[(22, 11)], 4), vec![]);
+        assert_eq!(
+            split_ranges(&[(22, 11), (u64::MAX - 3, u64::MAX)], 2),
+            vec![(u64::MAX - 3, u64::MAX - 2), (u64::MAX - 1, u64::MAX)]
+        );
+    }
+
+    // Ranges near repeated-digit ids like 123123 so both parts have ids to find.
+    fn random_ranges(rng: &mut Rng) -> Vec<(u64, u64)> {
+        (0..rng.range(1..=6))
+            .map(|_| {
+                let pattern = rng.range(1..=999);
+                let repeats = rng.range(2..=3) as usize;
+                let near: u64 = pattern.to_string().repeat(repeats).parse().unwrap();
+                let start = near.saturating_sub(rng.range(0..=100)).max(1);
+                let end = start + rng.range(0..=200);
+                // the parser accepts reversed ranges too
+                if rng.chance(10) {
+                    (end, start)
+                } else {
+                    (start, end)
+                }
+            })
+            .collect()
+    }
+
+    fn shrink_ranges(ranges: &[(u64, u64)]) -> Vec<Vec<(u64, u64)>> {
+        differential::shrink_vec(ranges, |&(start, end)| {
+            if start > end {
+                return vec![(end, start)];
+            }
+            let shorter = differential::shrink_u64(end - start, 0)
+                .into_iter()
+                .map(|len| (start, start + len));
+            let lower = differential::shrink_u64(start, 1)
+                .into_iter()
+                .map(|from| (from, from + (end - start)));
+            shorter.chain(lower).collect()
+        })
+    }
+
+    #[test]
+    fn threaded_matches_serial() {
+        let checked = differential::run(
+            differential::seed(),
+            2000,
+            random_ranges,
+            |ranges| shrink_ranges(ranges),
+            |ranges| {
+                differential::all_equal(&[
+                    ("serial part 1", sum_all_invalid_ids_part1(ranges)),
+                    (
+                        "threaded part 1",
+                        sum_all_invalid_ids_threaded_part1(ranges),
+                    ),
+                ])?;
+                differential::all_equal(&[
+                    ("serial part 2", sum_all_invalid_ids_part2(ranges)),
+                    (
+                        "threaded part 2",
+                        sum_all_invalid_ids_threaded_part2(ranges),
+                    ),
+                ])
+            },
+        );
+        assert!(checked.is_ok(), "{}", checked.unwrap_err());
+    }
+
+    #[test]
+    fn readme_examples() {
+        let checked = common::examples::check::<Day02>(
+            include_str!("../README.md"),
+            include_str!("../examples.txt"),
+        );
+        assert!(matches!(checked, Ok(count) if count >= 2), "{:?}", checked);
+    }
+
+    #[test]
+    fn solution_trait() {
+        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+        let parsed = Day02::parse(input).unwrap();
+        assert_eq!(Day02::part1(&parsed), Ok(1227775554));
+        assert_eq!(Day02::part2(&parsed), Ok(4174379265));
+    }
+
+    #[test]
+    fn reports_malformed_ranges() {
+        let err = parse_id_ranges("11-22,95115").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 7, "95115"));
+
+        let err = parse_id_ranges("11-22,95-1x5").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 10, "1x5"));
+    }
+}
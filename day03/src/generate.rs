@@ -0,0 +1,28 @@
+use common::rng::Rng;
+
+const BANK_WIDTH: u64 = 100;
+
+// `size` banks of 100 digits from 1 to 9, as wide as the real ones.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            (0..BANK_WIDTH)
+                .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{max_voltage_n_digits, parse_banks};
+
+    #[test]
+    fn generates_solvable_banks() {
+        let banks = parse_banks(&input(&mut Rng::new(1), 50)).unwrap();
+        assert_eq!(banks.len(), 50);
+        assert!(max_voltage_n_digits(&banks, Some(12)).is_ok());
+    }
+}
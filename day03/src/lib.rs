@@ -0,0 +1,207 @@
+use common::rng::Rng;
+use common::{Error, Part, Solution, Variant};
+
+pub mod generate;
+#[cfg(feature = "reference")]
+pub mod reference;
+
+// One bank per line, as digit values.
+pub fn parse_banks(input: &str) -> Result<Vec<Vec<u8>>, Error> {
+    input
+        .lines()
+        .map(|bank| {
+            if let Some((idx, c)) = bank.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+                let text = &bank[idx..idx + c.len_utf8()];
+                return Err(Error::locate(3, input, text, "expected a digit"));
+            }
+            Ok(bank.bytes().map(|b| b - b'0').collect())
+        })
+        .collect()
+}
+
+// Rejects banks the digit picking below can't handle, so its unwraps hold.
+fn check_bank(idx: usize, bank: &[u8], n: usize) -> Result<(), Error> {
+    if bank.len() < n {
+        let text: String = bank.iter().map(|&digit| char::from(b'0' + digit)).collect();
+        return Err(Error::new(
+            3,
+            idx + 1,
+            1,
+            &text,
+            &format!("bank needs at least {} digits", n),
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn max_voltage_two_digits(banks: &[Vec<u8>]) -> Result<u64, Error> {
+    banks
+        .iter()
+        .enumerate()
+        .map(|(idx, bank)| -> Result<u64, Error> {
+            check_bank(idx, bank, 2)?;
+            let max_decimal = *bank.iter().take(bank.len() - 1).max().unwrap();
+            let max_decimal_position = bank.iter().position(|&d| d == max_decimal).unwrap();
+            let max_integer = *bank.iter().skip(max_decimal_position + 1).max().unwrap();
+            Ok(max_decimal as u64 * 10 + max_integer as u64)
+        })
+        .sum()
+}
+
+pub fn max_voltage_n_digits(banks: &[Vec<u8>], n: Option<usize>) -> Result<u64, Error> {
+    let n = n.unwrap_or(2);
+    banks
+        .iter()
+        .enumerate()
+        .map(|(idx, bank)| -> Result<u64, Error> {
+            check_bank(idx, bank, n)?;
+            let mut joltage = 0;
+            let mut start = 0;
+            for i in 1..=n {
+                let power = 10u64.pow((n - i) as u32);
+                let remaining = n - i + 1; // digits still to pick (including current)
+                let end = bank.len() - remaining + 1; // last valid position (exclusive)
+                let window = &bank[start..end];
+                let max_digit = *window.iter().max().unwrap();
+                let relative_position = window.iter().position(|&d| d == max_digit).unwrap();
+                start = start + relative_position + 1;
+                joltage += max_digit as u64 * power;
+            }
+            Ok(joltage)
+        })
+        .sum()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Vec<u8>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_banks(input.trim_end())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, Error> {
+        max_voltage_n_digits(input, Some(2))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, Error> {
+        max_voltage_n_digits(input, Some(12))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    #[cfg(feature = "reference")]
+    fn references() -> Vec<common::Reference> {
+        reference::references()
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![Variant {
+            name: "two-digits",
+            part: Part::One,
+            solve: |input| Ok(max_voltage_two_digits(input)?.to_string()),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::differential;
+    use common::rng::Rng;
+
+    #[test]
+    fn part_one() {
+        let banks = "987654321111111\n811111111111119\n234234234234278\n818181911112111";
+        let banks = parse_banks(banks).unwrap();
+        assert_eq!(max_voltage_two_digits(&banks), Ok(357));
+    }
+
+    #[test]
+    fn part_one_generalized() {
+        let banks = "987654321111111\n811111111111119\n234234234234278\n818181911112111";
+        let banks = parse_banks(banks).unwrap();
+        assert_eq!(max_voltage_n_digits(&banks, Some(2)), Ok(357));
+    }
+
+    #[test]
+    fn part_two() {
+        let banks = "987654321111111\n811111111111119\n234234234234278\n818181911112111";
+        let banks = parse_banks(banks).unwrap();
+        assert_eq!(max_voltage_n_digits(&banks, Some(12)), Ok(3121910778619));
+    }
+
+    #[test]
+    fn readme_examples() {
+        let checked = common::examples::check::<Day03>(
+            include_str!("../README.md"),
+            include_str!("../examples.txt"),
+        );
+        assert!(matches!(checked, Ok(count) if count >= 2), "{:?}", checked);
+    }
+
+    #[test]
+    fn solution_trait() {
+        let input = "987654321111111\n811111111111119\n234234234234278\n818181911112111";
+        let parsed = Day03::parse(input).unwrap();
+        assert_eq!(Day03::part1(&parsed), Ok(357));
+        assert_eq!(Day03::part2(&parsed), Ok(3121910778619));
+    }
+
+    #[test]
+    fn reports_malformed_banks() {
+        let err = parse_banks("987\n81a1").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "a"));
+
+        let banks = parse_banks("987654321111111\n8111").unwrap();
+        let err = max_voltage_n_digits(&banks, Some(12)).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "8111"));
+    }
+
+    // Some banks are too short on purpose: both must reject the same one.
+    fn random_banks(rng: &mut Rng) -> Vec<Vec<u8>> {
+        (0..rng.range(1..=5))
+            .map(|_| {
+                (0..rng.range(1..=20))
+                    .map(|_| rng.range(0..=9) as u8)
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn shrink_banks(banks: &[Vec<u8>]) -> Vec<Vec<Vec<u8>>> {
+        differential::shrink_vec(banks, |bank| {
+            differential::shrink_vec(bank, |&digit| {
+                differential::shrink_u64(digit as u64, 0)
+                    .into_iter()
+                    .map(|d| d as u8)
+                    .collect()
+            })
+        })
+    }
+
+    #[test]
+    fn two_digits_matches_n_digits() {
+        let checked = differential::run(
+            differential::seed(),
+            5000,
+            random_banks,
+            |banks| shrink_banks(banks),
+            |banks| {
+                differential::all_equal(&[
+                    ("two digits", max_voltage_two_digits(banks)),
+                    ("n digits", max_voltage_n_digits(banks, Some(2))),
+                ])
+            },
+        );
+        assert!(checked.is_ok(), "{}", checked.unwrap_err());
+    }
+}
@@ -0,0 +1,91 @@
+use common::{Error, Part, Reference};
+
+use crate::parse_banks;
+
+// Every pair of batteries, keeping the best.
+fn best_pair(bank: &[u8]) -> Option<u64> {
+    (0..bank.len())
+        .flat_map(|i| (i + 1..bank.len()).map(move |j| bank[i] as u64 * 10 + bank[j] as u64))
+        .max()
+}
+
+// Drops one battery at a time, each time trying every position and keeping
+// whichever leaves the biggest number, until `n` are left.
+fn best_by_dropping(bank: &[u8], n: usize) -> Option<u64> {
+    if bank.len() < n {
+        return None;
+    }
+
+    let mut kept = bank.to_vec();
+    while kept.len() > n {
+        let candidates = (0..kept.len()).map(|skip| {
+            let mut candidate = kept.clone();
+            candidate.remove(skip);
+            candidate
+        });
+        // same length, so comparing the digit lists compares the numbers
+        kept = candidates.max()?;
+    }
+
+    Some(
+        kept.iter()
+            .fold(0, |joltage, &digit| joltage * 10 + digit as u64),
+    )
+}
+
+fn total(input: &str, best: impl Fn(&[u8]) -> Option<u64>) -> Result<Option<String>, Error> {
+    let banks = parse_banks(input.trim_end())?;
+    let total: Option<u64> = banks.iter().map(|bank| best(bank)).sum();
+    Ok(total.map(|total| total.to_string()))
+}
+
+pub fn references() -> Vec<Reference> {
+    vec![
+        Reference {
+            part: Part::One,
+            solve: |input| total(input, best_pair),
+        },
+        Reference {
+            part: Part::Two,
+            solve: |input| total(input, |bank| best_by_dropping(bank, 12)),
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::max_voltage_n_digits;
+    use common::differential;
+    use common::rng::Rng;
+
+    fn random_bank(rng: &mut Rng) -> Vec<u8> {
+        (0..rng.range(2..=16))
+            .map(|_| rng.range(1..=9) as u8)
+            .collect()
+    }
+
+    #[test]
+    fn brute_force_matches_windows() {
+        let checked = differential::run(
+            differential::seed(),
+            3000,
+            |rng| (random_bank(rng), rng.range(1..=6) as usize),
+            |(bank, n)| {
+                differential::shrink_vec(bank, |_| Vec::new())
+                    .into_iter()
+                    .map(|bank| (bank, *n))
+                    .collect()
+            },
+            |(bank, n)| {
+                let windows = |n| max_voltage_n_digits(std::slice::from_ref(bank), Some(n)).ok();
+                differential::all_equal(&[
+                    ("windows", windows(*n)),
+                    ("dropping", best_by_dropping(bank, *n)),
+                ])?;
+                differential::all_equal(&[("windows", windows(2)), ("pairs", best_pair(bank))])
+            },
+        );
+        assert!(checked.is_ok(), "{}", checked.unwrap_err());
+    }
+}
@@ -0,0 +1,199 @@
+use common::grid::Grid;
+
+const BITS: usize = 64;
+
+// One bit per cell, set for a roll: cell `col` of a row is bit `col % 64` of
+// word `col / 64`, so the neighbours of 64 cells are counted at once with
+// shifts, instead of eight lookups per cell.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BitGrid {
+    width: usize,
+    words: usize,
+    rows: Vec<Vec<u64>>,
+}
+
+// Counts up to 4 per bit, one bit plane per binary digit, stopping at 4
+// since that's all the rule needs to know.
+#[derive(Default, Clone, Copy)]
+struct Counter {
+    ones: u64,
+    twos: u64,
+    four_or_more: u64,
+}
+
+impl Counter {
+    fn add(&mut self, bits: u64) {
+        let carry = self.ones & bits;
+        self.ones ^= bits;
+        self.four_or_more |= self.twos & carry;
+        self.twos ^= carry;
+    }
+}
+
+impl BitGrid {
+    pub fn from_grid(grid: &Grid<char>) -> BitGrid {
+        let width = grid.width();
+        let words = width.div_ceil(BITS);
+        let rows = grid
+            .rows()
+            .map(|row| {
+                let mut bits = vec![0u64; words];
+                for (col, &cell) in row.iter().enumerate() {
+                    if cell == '@' {
+                        bits[col / BITS] |= 1 << (col % BITS);
+                    }
+                }
+                bits
+            })
+            .collect();
+
+        BitGrid { width, words, rows }
+    }
+
+    // Adds a row's rolls to the counter three times: shifted so each cell
+    // sees its left neighbour, unshifted, and its right neighbour.
+    fn add_row(&self, counter: &mut [Counter], row: &[u64], include_centre: bool) {
+        for word in 0..self.words {
+            let before = if word > 0 { row[word - 1] } else { 0 };
+            let after = row.get(word + 1).copied().unwrap_or(0);
+
+            let left = (row[word] << 1) | (before >> (BITS - 1));
+            let right = (row[word] >> 1) | (after << (BITS - 1));
+            counter[word].add(left);
+            counter[word].add(right);
+            if include_centre {
+                counter[word].add(row[word]);
+            }
+        }
+    }
+
+    // One bit mask per row of the rolls with fewer than four rolls around.
+    pub fn accessible(&self) -> Vec<Vec<u64>> {
+        (0..self.rows.len())
+            .map(|row| {
+                let mut counter = vec![Counter::default(); self.words];
+                if row > 0 {
+                    self.add_row(&mut counter, &self.rows[row - 1], true);
+                }
+                self.add_row(&mut counter, &self.rows[row], false);
+                if let Some(below) = self.rows.get(row + 1) {
+                    self.add_row(&mut counter, below, true);
+                }
+
+                counter
+                    .iter()
+                    .zip(&self.rows[row])
+                    .map(|(counter, &rolls)| rolls & !counter.four_or_more)
+                    .collect()
+            })
+            .collect()
+    }
+
+    pub fn remove(&mut self, masks: &[Vec<u64>]) {
+        for (row, mask) in self.rows.iter_mut().zip(masks) {
+            for (word, &mask) in row.iter_mut().zip(mask) {
+                *word &= !mask;
+            }
+        }
+    }
+
+    // Back to characters, with the cells in `marked` shown as `x`.
+    pub fn to_grid(&self, marked: &[Vec<u64>]) -> Grid<char> {
+        let cells = self
+            .rows
+            .iter()
+            .zip(marked)
+            .flat_map(|(row, marked)| {
+                (0..self.width).map(move |col| {
+                    let bit = 1 << (col % BITS);
+                    if marked[col / BITS] & bit != 0 {
+                        'x'
+                    } else if row[col / BITS] & bit != 0 {
+                        '@'
+                    } else {
+                        '.'
+                    }
+                })
+            })
+            .collect();
+        Grid::from_vec(self.width, cells)
+    }
+}
+
+fn count(masks: &[Vec<u64>]) -> i32 {
+    masks
+        .iter()
+        .flatten()
+        .map(|word| word.count_ones())
+        .sum::<u32>() as i32
+}
+
+pub fn find_accessible_rolls_bitpacked(grid: &Grid<char>) -> (Grid<char>, i32) {
+    let bits = BitGrid::from_grid(grid);
+    let accessible = bits.accessible();
+    (bits.to_grid(&accessible), count(&accessible))
+}
+
+// Removes every accessible roll round after round, all in bit masks.
+pub fn remove_rolls_bitpacked(grid: &Grid<char>) -> (Grid<char>, i32) {
+    let mut bits = BitGrid::from_grid(grid);
+    let mut total = 0;
+
+    loop {
+        let accessible = bits.accessible();
+        let removed = count(&accessible);
+        if removed == 0 {
+            return (bits.to_grid(&accessible), total);
+        }
+        bits.remove(&accessible);
+        total += removed;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{find_accessible_rolls, generate, parse_grid, remove_rolls_in_rounds_serial};
+    use common::differential;
+
+    #[test]
+    fn counts_neighbours_like_the_example() {
+        let grid = parse_grid(
+            "..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n\
+             .@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.",
+        )
+        .unwrap();
+        assert_eq!(
+            find_accessible_rolls_bitpacked(&grid),
+            find_accessible_rolls(&grid)
+        );
+        assert_eq!(remove_rolls_bitpacked(&grid).1, 43);
+    }
+
+    // Wide enough for rows to span several words, so the shifts carry bits
+    // across word boundaries.
+    #[test]
+    fn matches_serial_across_words() {
+        let checked = differential::run(
+            differential::seed(),
+            25,
+            |rng| {
+                let size = rng.range(60..=130) as usize;
+                generate::input(rng, size)
+            },
+            |_| Vec::new(),
+            |input| {
+                let grid = parse_grid(input).unwrap();
+                differential::all_equal(&[
+                    ("serial", find_accessible_rolls(&grid)),
+                    ("bit-packed", find_accessible_rolls_bitpacked(&grid)),
+                ])?;
+                differential::all_equal(&[
+                    ("serial", remove_rolls_in_rounds_serial(&grid)),
+                    ("bit-packed", remove_rolls_bitpacked(&grid)),
+                ])
+            },
+        );
+        assert!(checked.is_ok(), "{}", checked.unwrap_err());
+    }
+}
@@ -0,0 +1,28 @@
+use common::rng::Rng;
+
+// About two thirds of the real grid's cells are rolls.
+const ROLL_PERCENT: u64 = 65;
+
+// A `size` x `size` grid of rolls (@) and empty floor (.).
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.chance(ROLL_PERCENT) { '@' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_grid;
+
+    #[test]
+    fn generates_a_square_grid() {
+        let grid = parse_grid(&input(&mut Rng::new(1), 30)).unwrap();
+        assert_eq!((grid.height(), grid.width()), (30, 30));
+    }
+}
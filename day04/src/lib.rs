@@ -0,0 +1,405 @@
+use common::grid::{Grid, Pos};
+use common::pool::{Pool, balanced};
+use common::rng::Rng;
+use common::{Error, Part, Solution, Variant};
+use std::collections::VecDeque;
+use std::{sync::Arc, thread};
+
+pub mod bits;
+pub mod generate;
+#[cfg(feature = "reference")]
+pub mod reference;
+
+fn get_neighbour_rolls_count(grid: &Grid<char>, position: Pos) -> i32 {
+    grid.neighbours8(position)
+        .filter(|&pos| grid[pos] == '@')
+        .count() as i32
+}
+
+// Each returns the grid with accessible rolls marked `x`, and their count.
+pub fn find_accessible_rolls(grid: &Grid<char>) -> (Grid<char>, i32) {
+    let mut accessible_rolls = grid.clone();
+    let mut x_count = 0;
+
+    for pos in grid.positions() {
+        if grid[pos] == '@' && get_neighbour_rolls_count(grid, pos) < 4 {
+            accessible_rolls[pos] = 'x';
+            x_count += 1;
+        }
+    }
+
+    (accessible_rolls, x_count)
+}
+
+pub fn find_accessible_rolls_thread_per_cell(grid: &Grid<char>) -> (Grid<char>, i32) {
+    let grid = Arc::new(grid.clone());
+
+    let handles: Vec<_> = grid
+        .positions()
+        .filter(|&pos| grid[pos] == '@')
+        .map(|pos| {
+            let grid = Arc::clone(&grid);
+            thread::spawn(move || {
+                let count = get_neighbour_rolls_count(&grid, pos);
+                if count < 4 { Some(pos) } else { None }
+            })
+        })
+        .collect();
+
+    let accessible_rolls: Vec<_> = handles
+        .into_iter()
+        .filter_map(|h| h.join().unwrap())
+        .collect();
+    // count x
+    let count = accessible_rolls.len() as i32;
+
+    // rebuild grid
+    let mut new_grid = (*grid).clone();
+    for pos in accessible_rolls {
+        new_grid[pos] = 'x';
+    }
+
+    (new_grid, count)
+}
+
+// Rows are batched into a few jobs per pool worker rather than one thread each.
+pub fn find_accessible_rolls_thread_per_row(grid: &Grid<char>) -> (Grid<char>, i32) {
+    let pool = Pool::shared();
+    let width = grid.width();
+
+    let batches = pool.map(&balanced(grid.height(), pool.jobs()), |rows| {
+        rows.clone()
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|pos| {
+                if grid[pos] == '@' {
+                    let count = get_neighbour_rolls_count(grid, pos);
+                    if count < 4 { 'x' } else { '@' }
+                } else {
+                    grid[pos]
+                }
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let results = Grid::from_vec(width, batches.into_iter().flatten().collect());
+    // count x
+    let x_count = results.iter().filter(|&&c| c == 'x').count() as i32;
+
+    (results, x_count)
+}
+
+pub fn parse_grid(input: &str) -> Result<Grid<char>, Error> {
+    Grid::parse(4, input, |c| match c {
+        '@' | '.' => Ok(c),
+        _ => Err("expected @ or ."),
+    })
+}
+
+// removes the marked rolls in place, ready for the next round
+fn remove_marked(grid: &mut Grid<char>) {
+    for cell in grid.iter_mut() {
+        if *cell == 'x' {
+            *cell = '.';
+        }
+    }
+}
+
+// Removes every accessible roll round after round until a round finds none.
+// A loop rather than a recursive call per round, so the stack doesn't grow
+// with the number of rounds on big grids.
+fn remove_rolls_in_rounds(
+    grid: &Grid<char>,
+    find: fn(&Grid<char>) -> (Grid<char>, i32),
+) -> (Grid<char>, i32) {
+    let (mut state, mut count) = find(grid);
+    let mut total = 0;
+    while count > 0 {
+        remove_marked(&mut state);
+        total += count;
+        (state, count) = find(&state);
+    }
+    (state, total)
+}
+
+pub fn remove_rolls_in_rounds_thread_per_row(grid: &Grid<char>) -> (Grid<char>, i32) {
+    remove_rolls_in_rounds(grid, find_accessible_rolls_thread_per_row)
+}
+
+pub fn remove_rolls_in_rounds_serial(grid: &Grid<char>) -> (Grid<char>, i32) {
+    remove_rolls_in_rounds(grid, find_accessible_rolls)
+}
+
+// Removes rolls one by one as they become accessible, instead of rescanning
+// the grid every round: each roll keeps a count of its neighbouring rolls,
+// and removing a roll only updates its eight neighbours, queueing any that
+// drop below four. The work is proportional to the rolls removed.
+pub fn remove_rolls_worklist(grid: &Grid<char>) -> (Grid<char>, i32) {
+    let mut grid = grid.clone();
+    let mut counts = Grid::from_vec(grid.width(), vec![0; grid.width() * grid.height()]);
+    let mut queue = VecDeque::new();
+
+    for pos in grid.positions().filter(|&pos| grid[pos] == '@') {
+        counts[pos] = get_neighbour_rolls_count(&grid, pos);
+        if counts[pos] < 4 {
+            queue.push_back(pos);
+        }
+    }
+
+    let mut removed = 0;
+    while let Some(pos) = queue.pop_front() {
+        grid[pos] = '.';
+        removed += 1;
+
+        for neighbour in grid.neighbours8(pos) {
+            if grid[neighbour] != '@' {
+                continue;
+            }
+            counts[neighbour] -= 1;
+            // queued exactly once, when it first drops below four
+            if counts[neighbour] == 3 {
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    (grid, removed)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_grid(input.trim_end())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, Error> {
+        Ok(find_accessible_rolls(input).1)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, Error> {
+        Ok(remove_rolls_worklist(input).1)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    #[cfg(feature = "reference")]
+    fn references() -> Vec<common::Reference> {
+        reference::references()
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant {
+                name: "thread-per-row",
+                part: Part::One,
+                solve: |input| Ok(find_accessible_rolls_thread_per_row(input).1.to_string()),
+            },
+            Variant {
+                name: "bit-packed",
+                part: Part::One,
+                solve: |input| Ok(bits::find_accessible_rolls_bitpacked(input).1.to_string()),
+            },
+            Variant {
+                name: "thread-per-row",
+                part: Part::Two,
+                solve: |input| Ok(remove_rolls_in_rounds_thread_per_row(input).1.to_string()),
+            },
+            Variant {
+                name: "serial",
+                part: Part::Two,
+                solve: |input| Ok(remove_rolls_in_rounds_serial(input).1.to_string()),
+            },
+            Variant {
+                name: "bit-packed",
+                part: Part::Two,
+                solve: |input| Ok(bits::remove_rolls_bitpacked(input).1.to_string()),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::differential;
+    use common::rng::Rng;
+
+    #[test]
+    fn it_works() {
+        let input = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+        let output = String::from(
+            "..xx.xx@x.
+x@@.@.@.@@
+@@@@@.x.@@
+@.@@@@..@.
+x@.@@@@.@x
+.@@@@@@@.@
+.@.@.@.@@@
+x.@@@.@@@@
+.@@@@@@@@.
+x.x.@@@.x.",
+        );
+        let (marked, count) = find_accessible_rolls(&parse_grid(input).unwrap());
+        assert_eq!((marked.to_string(), count), (output, 13));
+    }
+
+    #[test]
+    fn it_works_in_rounds() {
+        let input = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+        let output = String::from(
+            "..........
+..........
+..........
+....@@....
+...@@@@...
+...@@@@@..
+...@.@.@@.
+...@@.@@@.
+...@@@@@..
+....@@@...",
+        );
+        let (final_state, total) =
+            remove_rolls_in_rounds_thread_per_row(&parse_grid(input).unwrap());
+        assert_eq!((final_state.to_string(), total), (output.clone(), 43));
+
+        let (final_state, total) = remove_rolls_worklist(&parse_grid(input).unwrap());
+        assert_eq!((final_state.to_string(), total), (output, 43));
+    }
+
+    #[test]
+    fn readme_examples() {
+        let checked = common::examples::check::<Day04>(
+            include_str!("../README.md"),
+            include_str!("../examples.txt"),
+        );
+        assert!(matches!(checked, Ok(count) if count >= 2), "{:?}", checked);
+    }
+
+    #[test]
+    fn solution_trait() {
+        let input = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+        let parsed = Day04::parse(input).unwrap();
+        assert_eq!(Day04::part1(&parsed), Ok(13));
+        assert_eq!(Day04::part2(&parsed), Ok(43));
+    }
+
+    #[test]
+    fn reports_malformed_grids() {
+        let err = parse_grid("..@\n.#@").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "#"));
+
+        let err = parse_grid("..@\n.@").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, ".@"));
+
+        assert!(parse_grid("").is_err());
+    }
+
+    fn random_grid(rng: &mut Rng) -> Grid<char> {
+        let (height, width) = (rng.range(1..=7) as usize, rng.range(1..=7) as usize);
+        let density = rng.range(20..=90);
+        let cells = (0..height * width)
+            .map(|_| if rng.chance(density) { '@' } else { '.' })
+            .collect();
+        Grid::from_vec(width, cells)
+    }
+
+    // Drops a row or a column, or clears a single roll.
+    fn shrink_grid(grid: &Grid<char>) -> Vec<Grid<char>> {
+        let rows: Vec<Vec<char>> = grid.rows().map(<[char]>::to_vec).collect();
+        let mut candidates = Vec::new();
+        for row in 0..grid.height() {
+            let mut smaller = rows.clone();
+            smaller.remove(row);
+            candidates.extend(Grid::from_rows(smaller));
+        }
+        for col in 0..grid.width() {
+            let mut smaller = rows.clone();
+            smaller.iter_mut().for_each(|cells| {
+                cells.remove(col);
+            });
+            candidates.extend(Grid::from_rows(smaller));
+        }
+        for pos in grid.positions().filter(|&pos| grid[pos] == '@') {
+            let mut smaller = grid.clone();
+            smaller[pos] = '.';
+            candidates.push(smaller);
+        }
+
+        candidates
+    }
+
+    #[test]
+    fn parallel_variants_match_serial() {
+        let checked = differential::run(
+            differential::seed(),
+            2000,
+            random_grid,
+            shrink_grid,
+            |grid| {
+                differential::all_equal(&[
+                    ("serial", find_accessible_rolls(grid)),
+                    ("thread per row", find_accessible_rolls_thread_per_row(grid)),
+                    (
+                        "thread per cell",
+                        find_accessible_rolls_thread_per_cell(grid),
+                    ),
+                    ("bit-packed", bits::find_accessible_rolls_bitpacked(grid)),
+                ])
+            },
+        );
+        assert!(checked.is_ok(), "{}", checked.unwrap_err());
+    }
+
+    #[test]
+    fn worklist_matches_rounds() {
+        let checked = differential::run(
+            differential::seed(),
+            500,
+            random_grid,
+            shrink_grid,
+            |grid| {
+                differential::all_equal(&[
+                    ("rounds", remove_rolls_in_rounds_serial(grid)),
+                    ("worklist", remove_rolls_worklist(grid)),
+                    ("bit-packed", bits::remove_rolls_bitpacked(grid)),
+                ])
+            },
+        );
+        assert!(checked.is_ok(), "{}", checked.unwrap_err());
+    }
+}
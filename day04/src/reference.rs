@@ -0,0 +1,96 @@
+use common::grid::{Grid, Pos};
+use common::{Error, Part, Reference};
+
+use crate::parse_grid;
+
+const MAX_STEPS: usize = 1_000_000_000;
+
+// Looks at the 3x3 block around the roll, skipping cells off the grid.
+fn accessible(grid: &Grid<char>, (row, col): Pos) -> bool {
+    let mut rolls = 0;
+    for r in row.saturating_sub(1)..=row + 1 {
+        for c in col.saturating_sub(1)..=col + 1 {
+            if (r, c) != (row, col) && grid.get((r, c)) == Some(&'@') {
+                rolls += 1;
+            }
+        }
+    }
+    grid[(row, col)] == '@' && rolls < 4
+}
+
+fn count_accessible(input: &str) -> Result<Option<String>, Error> {
+    let grid = parse_grid(input.trim_end())?;
+    let count = grid
+        .positions()
+        .filter(|&pos| accessible(&grid, pos))
+        .count();
+    Ok(Some(count.to_string()))
+}
+
+// Removes a single roll at a time, rescanning the whole grid from the top
+// after each, rather than a round of every accessible roll at once.
+fn remove_one_at_a_time(input: &str) -> Result<Option<String>, Error> {
+    let mut grid = parse_grid(input.trim_end())?;
+    let cells = grid.width() * grid.height();
+    let rolls = grid.iter().filter(|&&c| c == '@').count();
+    if cells * rolls > MAX_STEPS {
+        return Ok(None);
+    }
+
+    let mut removed = 0;
+    loop {
+        let Some(pos) = grid.positions().find(|&pos| accessible(&grid, pos)) else {
+            return Ok(Some(removed.to_string()));
+        };
+        grid[pos] = '.';
+        removed += 1;
+    }
+}
+
+pub fn references() -> Vec<Reference> {
+    vec![
+        Reference {
+            part: Part::One,
+            solve: count_accessible,
+        },
+        Reference {
+            part: Part::Two,
+            solve: remove_one_at_a_time,
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day04, generate};
+    use common::Solution;
+    use common::differential;
+
+    #[test]
+    fn one_at_a_time_matches_rounds() {
+        let checked = differential::run(
+            differential::seed(),
+            500,
+            |rng| {
+                let size = rng.range(1..=12) as usize;
+                generate::input(rng, size)
+            },
+            |_| Vec::new(),
+            |input| {
+                let grid = Day04::parse(input).unwrap();
+                let rounds = (Day04::part1(&grid).unwrap(), Day04::part2(&grid).unwrap());
+                differential::all_equal(&[
+                    ("rounds", Some((rounds.0.to_string(), rounds.1.to_string()))),
+                    (
+                        "one at a time",
+                        count_accessible(input)
+                            .unwrap()
+                            .zip(remove_one_at_a_time(input).unwrap()),
+                    ),
+                ])
+            },
+        );
+        assert!(checked.is_ok(), "{}", checked.unwrap_err());
+    }
+}
@@ -0,0 +1,55 @@
+use common::rng::Rng;
+
+const MAX_ID: u64 = 500_000_000_000_000;
+const MAX_RANGE_LEN: u64 = 1_000_000_000_000;
+
+// An id inside one of `ranges`.
+fn inside(rng: &mut Rng, ranges: &[(u64, u64)]) -> u64 {
+    let &(start, end) = rng.pick(ranges);
+    rng.range(start..=end)
+}
+
+// `size` fresh id ranges, some overlapping, then a blank line and five times
+// as many inventory ids, about half of them inside a range.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut ranges: Vec<(u64, u64)> = Vec::new();
+    for _ in 0..size {
+        let start = if !ranges.is_empty() && rng.chance(20) {
+            inside(rng, &ranges)
+        } else {
+            rng.range(1..=MAX_ID)
+        };
+        ranges.push((start, start + rng.range(0..=MAX_RANGE_LEN)));
+    }
+
+    let items: Vec<u64> = (0..size * 5)
+        .map(|_| {
+            if !ranges.is_empty() && rng.chance(50) {
+                inside(rng, &ranges)
+            } else {
+                rng.range(1..=MAX_ID)
+            }
+        })
+        .collect();
+
+    let ranges: Vec<String> = ranges
+        .iter()
+        .map(|(start, end)| format!("{}-{}", start, end))
+        .collect();
+    let items: Vec<String> = items.iter().map(u64::to_string).collect();
+
+    format!("{}\n\n{}", ranges.join("\n"), items.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{count_stale_items, parse_input};
+
+    #[test]
+    fn generates_ranges_and_inventory() {
+        let database = parse_input(&input(&mut Rng::new(1), 40)).unwrap();
+        assert_eq!((database.0.len(), database.1.len()), (40, 200));
+        assert!(count_stale_items(&database) > 0);
+    }
+}
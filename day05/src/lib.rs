@@ -0,0 +1,169 @@
+use common::rng::Rng;
+use common::{Error, Solution};
+
+pub mod generate;
+#[cfg(feature = "reference")]
+pub mod reference;
+
+fn parse_id(input: &str, id: &str) -> Result<i64, Error> {
+    id.parse::<i64>()
+        .map_err(|_| Error::locate(5, input, id, "invalid id"))
+}
+
+// sorted fresh id ranges and the inventory item ids
+pub type Database = (Vec<(i64, i64)>, Vec<i64>);
+
+pub fn parse_input(input: &str) -> Result<Database, Error> {
+    let Some((ranges, items)) = input.split_once("\n\n") else {
+        let end = &input[input.len()..];
+        return Err(Error::locate(
+            5,
+            input,
+            end,
+            "missing blank line before the inventory",
+        ));
+    };
+
+    let mut fresh_items: Vec<(i64, i64)> = ranges
+        .lines()
+        .map(|range_line| {
+            let Some((start, end)) = range_line.split_once('-') else {
+                return Err(Error::locate(
+                    5,
+                    input,
+                    range_line,
+                    "expected a range like 3-5",
+                ));
+            };
+            Ok((parse_id(input, start)?, parse_id(input, end)?))
+        })
+        .collect::<Result<_, _>>()?;
+
+    fresh_items.sort();
+
+    let inventory = items
+        .lines()
+        .map(|item| parse_id(input, item))
+        .collect::<Result<_, _>>()?;
+
+    Ok((fresh_items, inventory))
+}
+
+pub fn count_stale_items((fresh_items, inventory): &Database) -> i64 {
+    let mut count = 0;
+
+    for &item_id in inventory {
+        let idx = fresh_items.partition_point(|&(start, _)| start <= item_id);
+        for (start, end) in &fresh_items[0..idx] {
+            if *start <= item_id && item_id <= *end {
+                count += 1;
+                break;
+            }
+        }
+    }
+
+    count
+}
+
+pub fn count_fresh_items((fresh_items, _): &Database) -> i64 {
+    // walk though the fresh item ranges and merge them if they overlap
+    let mut merged_ranges = Vec::new();
+    let Some(&first_range) = fresh_items.first() else {
+        return 0;
+    };
+    let mut current_range = first_range;
+
+    for next_range in fresh_items.iter().skip(1) {
+        if current_range.1 + 1 >= next_range.0 {
+            current_range.1 = current_range.1.max(next_range.1);
+        } else {
+            merged_ranges.push(current_range);
+            current_range = (next_range.0, next_range.1);
+        }
+    }
+    merged_ranges.push(current_range);
+
+    // count all the idx in all the ranges
+    let mut count = 0;
+    for &(start, end) in &merged_ranges {
+        count += end - start + 1;
+    }
+
+    count
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Database;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input.trim_end())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, Error> {
+        Ok(count_stale_items(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, Error> {
+        Ok(count_fresh_items(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    #[cfg(feature = "reference")]
+    fn references() -> Vec<common::Reference> {
+        reference::references()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_stale_items() {
+        let input = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32";
+        let output = 3;
+        assert_eq!(count_stale_items(&parse_input(input).unwrap()), output);
+    }
+
+    #[test]
+    fn test_count_fresh_items() {
+        let input = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32";
+        let output = 14;
+        assert_eq!(count_fresh_items(&parse_input(input).unwrap()), output);
+    }
+
+    #[test]
+    fn readme_examples() {
+        let checked = common::examples::check::<Day05>(
+            include_str!("../README.md"),
+            include_str!("../examples.txt"),
+        );
+        assert!(matches!(checked, Ok(count) if count >= 2), "{:?}", checked);
+    }
+
+    #[test]
+    fn solution_trait() {
+        let input = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32";
+        let parsed = Day05::parse(input).unwrap();
+        assert_eq!(Day05::part1(&parsed), Ok(3));
+        assert_eq!(Day05::part2(&parsed), Ok(14));
+    }
+
+    #[test]
+    fn reports_malformed_input() {
+        let err = parse_input("3-5\n10-1x\n\n1").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "1x"));
+
+        let err = parse_input("3-5\n10-14").unwrap_err();
+        assert_eq!(err.line, 2);
+    }
+}
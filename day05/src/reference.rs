@@ -0,0 +1,98 @@
+use std::collections::HashSet;
+
+use common::{Error, Part, Reference};
+
+use crate::parse_input;
+
+const MAX_IDS: i64 = 10_000_000;
+
+// Checks every range for every item, no sorting or binary search.
+fn count_in_any_range(input: &str) -> Result<Option<String>, Error> {
+    let (ranges, items) = parse_input(input.trim_end())?;
+    let count = items
+        .iter()
+        .filter(|&&item| {
+            ranges
+                .iter()
+                .any(|&(start, end)| start <= item && item <= end)
+        })
+        .count();
+    Ok(Some(count.to_string()))
+}
+
+// Collects every fresh id one by one instead of merging the ranges.
+fn count_each_id(input: &str) -> Result<Option<String>, Error> {
+    let (ranges, _) = parse_input(input.trim_end())?;
+    let ids: i64 = ranges.iter().map(|&(start, end)| end - start + 1).sum();
+    if ids > MAX_IDS {
+        return Ok(None);
+    }
+
+    let fresh: HashSet<i64> = ranges
+        .iter()
+        .flat_map(|&(start, end)| start..=end)
+        .collect();
+    Ok(Some(fresh.len().to_string()))
+}
+
+pub fn references() -> Vec<Reference> {
+    vec![
+        Reference {
+            part: Part::One,
+            solve: count_in_any_range,
+        },
+        Reference {
+            part: Part::Two,
+            solve: count_each_id,
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{count_fresh_items, count_stale_items};
+    use common::differential;
+    use common::rng::Rng;
+
+    // Small ids so that touching and nested ranges are common.
+    fn random_database(rng: &mut Rng) -> String {
+        let ranges: Vec<String> = (0..rng.range(1..=8))
+            .map(|_| {
+                let start = rng.range(0..=60);
+                format!("{}-{}", start, start + rng.range(0..=15))
+            })
+            .collect();
+        let items: Vec<String> = (0..rng.range(1..=8))
+            .map(|_| rng.range(0..=80).to_string())
+            .collect();
+        format!("{}\n\n{}", ranges.join("\n"), items.join("\n"))
+    }
+
+    #[test]
+    fn brute_force_matches_merging() {
+        let checked = differential::run(
+            differential::seed(),
+            3000,
+            random_database,
+            |_| Vec::new(),
+            |input| {
+                let database = parse_input(input).unwrap();
+                let optimized = (count_stale_items(&database), count_fresh_items(&database));
+                differential::all_equal(&[
+                    (
+                        "merged",
+                        Some((optimized.0.to_string(), optimized.1.to_string())),
+                    ),
+                    (
+                        "brute force",
+                        count_in_any_range(input)
+                            .unwrap()
+                            .zip(count_each_id(input).unwrap()),
+                    ),
+                ])
+            },
+        );
+        assert!(checked.is_ok(), "{}", checked.unwrap_err());
+    }
+}
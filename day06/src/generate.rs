@@ -0,0 +1,55 @@
+use common::rng::Rng;
+
+const NUMBER_ROWS: usize = 4;
+
+// `size` problems side by side: four rows of 1 to 4 digit numbers, aligned
+// left or right within their problem, over a row of operators. Every line is
+// padded to the full width like the real worksheet.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut lines = vec![String::new(); NUMBER_ROWS + 1];
+
+    for problem in 0..size {
+        if problem > 0 {
+            lines.iter_mut().for_each(|line| line.push(' '));
+        }
+
+        // at least one number spans the whole problem, or its columns would
+        // read as separators
+        let width = rng.range(1..=4) as usize;
+        let widest = rng.index(NUMBER_ROWS);
+        let align_left = rng.chance(50);
+        for (row, line) in lines.iter_mut().take(NUMBER_ROWS).enumerate() {
+            let digits = if row == widest {
+                width
+            } else {
+                rng.range(1..=width as u64) as usize
+            };
+            let number: String = (0..digits)
+                .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+                .collect();
+            if align_left {
+                line.push_str(&format!("{:<width$}", number));
+            } else {
+                line.push_str(&format!("{:>width$}", number));
+            }
+        }
+
+        let operator = if rng.chance(50) { '+' } else { '*' };
+        lines[NUMBER_ROWS].push_str(&format!("{:<width$}", operator));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_worksheet, part_one, part_two};
+
+    #[test]
+    fn generates_a_worksheet() {
+        let problems = parse_worksheet(&input(&mut Rng::new(1), 100)).unwrap();
+        assert_eq!(problems.len(), 100);
+        assert!(part_one(&problems) > 0 && part_two(&problems) > 0);
+    }
+}
@@ -0,0 +1,279 @@
+use std::num::{IntErrorKind, ParseIntError};
+
+use common::rng::Rng;
+use common::{Error, Solution};
+
+pub mod generate;
+#[cfg(feature = "reference")]
+pub mod reference;
+
+// One problem of the worksheet, with its numbers read both ways.
+#[derive(Debug, PartialEq)]
+pub struct Problem {
+    operator: char,
+    // read left to right along each row (part one)
+    row_numbers: Vec<i64>,
+    // read top to bottom down each column (part two)
+    column_numbers: Vec<i64>,
+}
+
+fn apply(operator: char, numbers: &[i64]) -> i64 {
+    match operator {
+        '*' => numbers.iter().product(),
+        _ => numbers.iter().sum(),
+    }
+}
+
+// Finds the first character of `line` outside of `allowed`.
+fn check_chars(input: &str, line: &str, allowed: &[char], message: &str) -> Result<(), Error> {
+    match line.char_indices().find(|(_, c)| !allowed.contains(c)) {
+        Some((idx, c)) => Err(Error::locate(
+            6,
+            input,
+            &line[idx..idx + c.len_utf8()],
+            message,
+        )),
+        None => Ok(()),
+    }
+}
+
+// Only digits and spaces get this far, so a number is either too large or,
+// along a row, split by spaces that another row's digits keep from being a
+// separator.
+fn parse_number(text: &str) -> Result<i64, &'static str> {
+    text.parse().map_err(|err: ParseIntError| match err.kind() {
+        IntErrorKind::PosOverflow => "number too large",
+        _ => "expected a single number",
+    })
+}
+
+// The part of `line` in columns start..end, which may be cut short.
+fn columns(line: &str, start: usize, end: usize) -> &str {
+    line.get(start..end.min(line.len())).unwrap_or("")
+}
+
+pub fn parse_worksheet(input: &str) -> Result<Vec<Problem>, Error> {
+    let lines = input.lines().collect::<Vec<_>>();
+    // the worksheet needs at least one row of numbers and the row of operators
+    let Some((operators, rows)) = lines.split_last().filter(|(_, rows)| !rows.is_empty()) else {
+        let end = &input[input.len()..];
+        return Err(Error::locate(6, input, end, "missing row of operators"));
+    };
+
+    let digits = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9', ' '];
+    for row in rows {
+        check_chars(input, row, &digits, "expected a digit")?;
+    }
+    check_chars(input, operators, &['+', '*', ' '], "expected + or *")?;
+
+    // every line is ASCII now, so bytes and columns line up
+    let cell = |line: &str, col: usize| line.as_bytes().get(col).copied().unwrap_or(b' ');
+    let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+    let is_separator = |col: usize| lines.iter().all(|line| cell(line, col) == b' ');
+
+    let mut problems = Vec::new();
+    let mut start = 0;
+    while start < width {
+        if is_separator(start) {
+            start += 1;
+            continue;
+        }
+        let end = (start..width)
+            .find(|&col| is_separator(col))
+            .unwrap_or(width);
+
+        let Some(operator) = columns(operators, start, end).trim().chars().next() else {
+            return Err(Error::locate(
+                6,
+                input,
+                operators,
+                "row is missing an operator",
+            ));
+        };
+
+        let row_numbers = rows
+            .iter()
+            .map(|row| {
+                let number = columns(row, start, end).trim();
+                if number.is_empty() {
+                    return Err(Error::locate(6, input, row, "row is missing a number"));
+                }
+                parse_number(number).map_err(|message| Error::locate(6, input, number, message))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let column_numbers = (start..end)
+            .filter_map(|col| {
+                // errors point at the last digit of the column
+                let last_row = rows
+                    .iter()
+                    .rposition(|row| cell(row, col).is_ascii_digit())?;
+                let digits = rows
+                    .iter()
+                    .map(|row| cell(row, col))
+                    .filter(u8::is_ascii_digit)
+                    .map(char::from)
+                    .collect::<String>();
+                Some(
+                    parse_number(&digits)
+                        .map_err(|message| Error::new(6, last_row + 1, col + 1, &digits, message)),
+                )
+            })
+            .collect::<Result<_, _>>()?;
+
+        problems.push(Problem {
+            operator,
+            row_numbers,
+            column_numbers,
+        });
+        start = end;
+    }
+
+    Ok(problems)
+}
+
+pub fn part_one(problems: &[Problem]) -> i64 {
+    problems
+        .iter()
+        .map(|problem| apply(problem.operator, &problem.row_numbers))
+        .sum()
+}
+
+pub fn part_two(problems: &[Problem]) -> i64 {
+    problems
+        .iter()
+        .map(|problem| apply(problem.operator, &problem.column_numbers))
+        .sum()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<Problem>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        // keep leading spaces, they are part of the first row's columns
+        parse_worksheet(input.trim_end_matches('\n'))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, Error> {
+        Ok(part_one(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, Error> {
+        Ok(part_two(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    #[cfg(feature = "reference")]
+    fn references() -> Vec<common::Reference> {
+        reference::references()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = [
+            "123 328  51 64 ",
+            " 45 64  387 23 ",
+            "  6 98  215 314",
+            "*   +   *   +  ",
+        ]
+        .join("\n");
+        let expected_output = 4277556;
+
+        assert_eq!(part_one(&parse_worksheet(&input).unwrap()), expected_output);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = [
+            "123 328  51 64 ",
+            " 45 64  387 23 ",
+            "  6 98  215 314",
+            "*   +   *   +  ",
+        ]
+        .join("\n");
+        let expected_output = 3263827;
+
+        assert_eq!(part_two(&parse_worksheet(&input).unwrap()), expected_output);
+    }
+
+    #[test]
+    fn readme_examples() {
+        let checked = common::examples::check::<Day06>(
+            include_str!("../README.md"),
+            include_str!("../examples.txt"),
+        );
+        assert!(matches!(checked, Ok(count) if count >= 2), "{:?}", checked);
+    }
+
+    #[test]
+    fn solution_trait() {
+        let input = [
+            "123 328  51 64 ",
+            " 45 64  387 23 ",
+            "  6 98  215 314",
+            "*   +   *   +  ",
+        ]
+        .join("\n");
+        let parsed = Day06::parse(&input).unwrap();
+        assert_eq!(Day06::part1(&parsed), Ok(4277556));
+        assert_eq!(Day06::part2(&parsed), Ok(3263827));
+    }
+
+    #[test]
+    fn reports_malformed_worksheets() {
+        let input = ["123 328", " 45 6x ", "*   +  "].join("\n");
+        let err = parse_worksheet(&input).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 6, "x"));
+
+        let input = ["123 328", " 45 64 ", "*   -  "].join("\n");
+        let err = parse_worksheet(&input).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 5, "-"));
+
+        let input = ["123 328", " 45    ", "*   +  "].join("\n");
+        let err = parse_worksheet(&input).unwrap_err();
+        assert_eq!(
+            (err.line, err.message.as_str()),
+            (2, "row is missing a number")
+        );
+
+        let err = parse_worksheet("123 328").unwrap_err();
+        assert_eq!(err.message, "missing row of operators");
+
+        let input = ["12 3", "4567", "+   "].join("\n");
+        let err = parse_worksheet(&input).unwrap_err();
+        assert_eq!(
+            (err.line, err.text.as_str(), err.message.as_str()),
+            (1, "12 3", "expected a single number")
+        );
+
+        let input = ["99999999999999999999", "+"].join("\n");
+        let err = parse_worksheet(&input).unwrap_err();
+        assert_eq!((err.line, err.message.as_str()), (1, "number too large"));
+    }
+
+    #[test]
+    fn reports_column_numbers_on_their_last_row() {
+        let mut lines = vec![" 9"; 20];
+        lines[0] = "19";
+        lines.push("+ ");
+        let err = parse_worksheet(&lines.join("\n")).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.message.as_str()),
+            (20, 2, "number too large")
+        );
+    }
+}
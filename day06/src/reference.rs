@@ -0,0 +1,138 @@
+use common::{Error, Part, Reference};
+
+// The worksheet as a grid of characters, short lines padded with spaces.
+fn grid(input: &str) -> Vec<Vec<char>> {
+    let lines: Vec<&str> = input.trim_end_matches('\n').lines().collect();
+    let width = lines
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .map(|line| {
+            let mut cells: Vec<char> = line.chars().collect();
+            cells.resize(width, ' ');
+            cells
+        })
+        .collect()
+}
+
+// Turns the grid on its side and splits it into problems at the blank
+// columns, instead of scanning for separators in place. Each problem is
+// (operator, numbers) with the numbers read along rows or down columns.
+fn problems(input: &str, by_column: bool) -> Result<Vec<(char, Vec<i64>)>, Error> {
+    let grid = grid(input);
+    let Some((operators, rows)) = grid.split_last() else {
+        return Err(Error::new(6, 1, 1, "", "empty worksheet"));
+    };
+    let columns: Vec<Vec<char>> = (0..operators.len())
+        .map(|col| grid.iter().map(|row| row[col]).collect())
+        .collect();
+
+    let mut problems = Vec::new();
+    let mut start = 0;
+    for group in columns.split(|column| column.iter().all(|&c| c == ' ')) {
+        let cols = start..start + group.len();
+        // skip past the blank column too
+        start = cols.end + 1;
+        if group.is_empty() {
+            continue;
+        }
+        let Some(operator) = operators[cols.clone()].iter().find(|&&c| c != ' ') else {
+            return Err(Error::new(
+                6,
+                grid.len(),
+                cols.start + 1,
+                "",
+                "missing operator",
+            ));
+        };
+
+        let texts: Vec<String> = if by_column {
+            group
+                .iter()
+                .map(|column| column[..rows.len()].iter().filter(|&&c| c != ' ').collect())
+                .collect()
+        } else {
+            rows.iter()
+                .map(|row| row[cols.clone()].iter().filter(|&&c| c != ' ').collect())
+                .collect()
+        };
+        let numbers = texts
+            .iter()
+            .map(|text| {
+                text.parse()
+                    .map_err(|_| Error::new(6, 1, cols.start + 1, text, "invalid number"))
+            })
+            .collect::<Result<_, _>>()?;
+        problems.push((*operator, numbers));
+    }
+
+    Ok(problems)
+}
+
+fn grand_total(input: &str, by_column: bool) -> Result<Option<String>, Error> {
+    let total: i64 = problems(input, by_column)?
+        .iter()
+        .map(|(operator, numbers)| match operator {
+            '*' => numbers.iter().product::<i64>(),
+            _ => numbers.iter().sum(),
+        })
+        .sum();
+    Ok(Some(total.to_string()))
+}
+
+pub fn references() -> Vec<Reference> {
+    vec![
+        Reference {
+            part: Part::One,
+            solve: |input| grand_total(input, false),
+        },
+        Reference {
+            part: Part::Two,
+            solve: |input| grand_total(input, true),
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, parse_worksheet, part_one, part_two};
+    use common::differential;
+
+    #[test]
+    fn transposed_matches_parser() {
+        let checked = differential::run(
+            differential::seed(),
+            2000,
+            |rng| {
+                let size = rng.range(1..=12) as usize;
+                generate::input(rng, size)
+            },
+            |_| Vec::new(),
+            |input| {
+                let problems = parse_worksheet(input).unwrap();
+                let parsed = (part_one(&problems), part_two(&problems));
+                differential::all_equal(&[
+                    ("parser", Some((parsed.0.to_string(), parsed.1.to_string()))),
+                    (
+                        "transposed",
+                        grand_total(input, false)
+                            .unwrap()
+                            .zip(grand_total(input, true).unwrap()),
+                    ),
+                ])
+            },
+        );
+        assert!(checked.is_ok(), "{}", checked.unwrap_err());
+    }
+
+    #[test]
+    fn reports_unreadable_worksheets() {
+        assert!(grand_total("", false).is_err());
+        assert!(grand_total("1 2\n   ", false).is_err());
+        assert!(grand_total("1 x\n*  ", true).is_err());
+    }
+}
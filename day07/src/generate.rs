@@ -0,0 +1,46 @@
+use common::rng::Rng;
+
+// `size` rows of splitters below the S, spreading out one column per row
+// like the real manifold, with an empty row between each. Big manifolds get
+// fewer splitters so the part 2 timeline count stays within an i64.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let width = 2 * size + 3;
+    let center = size + 1;
+    let splitter_percent = (4000 / size.max(1) as u64).min(50);
+
+    let empty = ".".repeat(width);
+    let mut lines = vec![format!("{}S{}", &empty[..center], &empty[center + 1..])];
+    for row in 0..size {
+        let mut line = vec!['.'; width];
+        for col in (center - row..=center + row).step_by(2) {
+            if row == 0 || rng.chance(splitter_percent) {
+                line[col] = '^';
+            }
+        }
+        lines.push(empty.clone());
+        lines.push(line.into_iter().collect());
+    }
+    lines.push(empty);
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_grid, part_one, part_two};
+
+    #[test]
+    fn generates_a_manifold() {
+        let manifold = parse_grid(&input(&mut Rng::new(1), 70)).unwrap();
+        assert_eq!((manifold.0.height(), manifold.0.width()), (142, 143));
+        assert!(part_one(&manifold) > 0);
+        assert!(part_two(&manifold) > 1);
+    }
+
+    #[test]
+    fn large_manifolds_dont_overflow() {
+        let manifold = parse_grid(&input(&mut Rng::new(1), 2000)).unwrap();
+        assert!(part_two(&manifold) > 0);
+    }
+}
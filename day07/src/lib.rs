@@ -0,0 +1,231 @@
+use common::grid::{Grid, LEFT, Pos, RIGHT};
+use common::rng::Rng;
+use common::{Error, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub mod generate;
+#[cfg(feature = "reference")]
+pub mod reference;
+
+// the grid and the column the beam starts from
+pub type Manifold = (Grid<char>, usize);
+
+// Splitters on the edge are fine: the beam that would leave the manifold
+// sideways is just lost (see `split`).
+pub fn parse_grid(input: &str) -> Result<Manifold, Error> {
+    let grid = Grid::parse(7, input, |c| match c {
+        '.' | 'S' | '^' => Ok(c),
+        _ => Err("expected ., ^ or S"),
+    })?;
+
+    let Some((0, start)) = grid.find(&'S') else {
+        let first_line = input.lines().next().unwrap_or(input);
+        return Err(Error::locate(
+            7,
+            input,
+            first_line,
+            "no starting beam found",
+        ));
+    };
+
+    Ok((grid, start))
+}
+
+// The columns a beam carries on in after hitting the splitter at `pos`;
+// a side off the grid leaves the manifold and is dropped.
+fn split(grid: &Grid<char>, pos: Pos) -> impl Iterator<Item = usize> + '_ {
+    [LEFT, RIGHT]
+        .into_iter()
+        .filter_map(move |dir| grid.step(pos, dir))
+        .map(|(_, col)| col)
+}
+
+pub fn part_one((grid, start): &Manifold) -> i32 {
+    let mut split_count = 0;
+
+    let mut beam_columns_idx: HashSet<usize> = HashSet::new();
+    beam_columns_idx.insert(*start);
+
+    for row in 1..grid.height() {
+        let mut next: HashSet<usize> = HashSet::new();
+        for &col in &beam_columns_idx {
+            match grid[(row, col)] {
+                '^' => {
+                    next.extend(split(grid, (row, col)));
+                    split_count += 1;
+                }
+                _ => {
+                    next.insert(col);
+                }
+            }
+        }
+        beam_columns_idx = next;
+    }
+
+    split_count
+}
+
+pub fn part_two((grid, start): &Manifold) -> i64 {
+    let mut timelines: HashMap<usize, i64> = HashMap::new();
+    timelines.insert(*start, 1);
+
+    for row in 1..grid.height() {
+        let mut next: HashMap<usize, i64> = HashMap::new();
+
+        for (&col, &count) in &timelines {
+            match grid[(row, col)] {
+                '^' => {
+                    for side in split(grid, (row, col)) {
+                        *next.entry(side).or_insert(0) += count;
+                    }
+                }
+                _ => {
+                    *next.entry(col).or_insert(0) += count;
+                }
+            }
+        }
+
+        timelines = next;
+    }
+
+    timelines.values().sum()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Manifold;
+    type Answer1 = i32;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_grid(input.trim_end())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, Error> {
+        Ok(part_one(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, Error> {
+        Ok(part_two(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    #[cfg(feature = "reference")]
+    fn references() -> Vec<common::Reference> {
+        reference::references()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = [
+            ".......S.......",
+            "...............",
+            ".......^.......",
+            "...............",
+            "......^.^......",
+            "...............",
+            ".....^.^.^.....",
+            "...............",
+            "....^.^...^....",
+            "...............",
+            "...^.^...^.^...",
+            "...............",
+            "..^...^.....^..",
+            "...............",
+            ".^.^.^.^.^...^.",
+            "...............",
+        ]
+        .join("\n");
+        let expected_output = 21;
+
+        assert_eq!(part_one(&parse_grid(&input).unwrap()), expected_output);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = [
+            ".......S.......",
+            "...............",
+            ".......^.......",
+            "...............",
+            "......^.^......",
+            "...............",
+            ".....^.^.^.....",
+            "...............",
+            "....^.^...^....",
+            "...............",
+            "...^.^...^.^...",
+            "...............",
+            "..^...^.....^..",
+            "...............",
+            ".^.^.^.^.^...^.",
+            "...............",
+        ]
+        .join("\n");
+        let expected_output = 40;
+
+        assert_eq!(part_two(&parse_grid(&input).unwrap()), expected_output);
+    }
+
+    #[test]
+    fn readme_examples() {
+        let checked = common::examples::check::<Day07>(
+            include_str!("../README.md"),
+            include_str!("../examples.txt"),
+        );
+        assert!(matches!(checked, Ok(count) if count >= 2), "{:?}", checked);
+    }
+
+    #[test]
+    fn solution_trait() {
+        let input = [
+            ".......S.......",
+            "...............",
+            ".......^.......",
+            "...............",
+            "......^.^......",
+            "...............",
+            ".....^.^.^.....",
+            "...............",
+            "....^.^...^....",
+            "...............",
+            "...^.^...^.^...",
+            "...............",
+            "..^...^.....^..",
+            "...............",
+            ".^.^.^.^.^...^.",
+            "...............",
+        ]
+        .join("\n");
+        let parsed = Day07::parse(&input).unwrap();
+        assert_eq!(Day07::part1(&parsed), Ok(21));
+        assert_eq!(Day07::part2(&parsed), Ok(40));
+    }
+
+    #[test]
+    fn drops_beams_leaving_the_sides() {
+        let manifold = parse_grid("S..\n^..\n.^.\n...").unwrap();
+        assert_eq!(part_one(&manifold), 2);
+        assert_eq!(part_two(&manifold), 2);
+    }
+
+    #[test]
+    fn reports_malformed_manifolds() {
+        let err = parse_grid("..S..\n..^..\n.^#..").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 3, "#"));
+
+        let err = parse_grid(".....\n..^..").unwrap_err();
+        assert_eq!(err.message, "no starting beam found");
+    }
+}
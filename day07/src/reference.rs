@@ -0,0 +1,131 @@
+use common::grid::{Grid, Pos};
+use common::{Error, Part, Reference};
+
+use crate::parse_grid;
+
+const MAX_TIMELINES: u64 = 1_000_000;
+
+// Draws the beams into the grid row by row, then counts the splitters that
+// have a beam right above them.
+fn count_splits(input: &str) -> Result<Option<String>, Error> {
+    let (mut grid, start) = parse_grid(input.trim_end())?;
+    grid[(0, start)] = '|';
+
+    for row in 1..grid.height() {
+        for col in 0..grid.width() {
+            if grid[(row - 1, col)] != '|' {
+                continue;
+            }
+            if grid[(row, col)] == '^' {
+                // beams off the sides leave the manifold
+                for side in [col.checked_sub(1), Some(col + 1)].into_iter().flatten() {
+                    if let Some(cell) = grid.get_mut((row, side)) {
+                        *cell = '|';
+                    }
+                }
+            } else {
+                grid[(row, col)] = '|';
+            }
+        }
+    }
+
+    let splits = grid
+        .positions()
+        .filter(|&(row, col)| row > 0 && grid[(row, col)] == '^' && grid[(row - 1, col)] == '|')
+        .count();
+    Ok(Some(splits.to_string()))
+}
+
+// Follows every timeline to the bottom on its own, no counting per column.
+// Gives up once there are too many to walk.
+fn walk(grid: &Grid<char>, (row, col): Pos, timelines: &mut u64) -> Option<()> {
+    if *timelines > MAX_TIMELINES {
+        return None;
+    }
+    if row + 1 == grid.height() {
+        *timelines += 1;
+        return Some(());
+    }
+
+    if grid[(row + 1, col)] == '^' {
+        // a timeline leaving off the side doesn't reach the bottom
+        for side in [col.checked_sub(1), Some(col + 1)].into_iter().flatten() {
+            if side < grid.width() {
+                walk(grid, (row + 1, side), timelines)?;
+            }
+        }
+        Some(())
+    } else {
+        walk(grid, (row + 1, col), timelines)
+    }
+}
+
+fn count_timelines(input: &str) -> Result<Option<String>, Error> {
+    let (grid, start) = parse_grid(input.trim_end())?;
+    let mut timelines = 0;
+    Ok(walk(&grid, (0, start), &mut timelines).map(|_| timelines.to_string()))
+}
+
+pub fn references() -> Vec<Reference> {
+    vec![
+        Reference {
+            part: Part::One,
+            solve: count_splits,
+        },
+        Reference {
+            part: Part::Two,
+            solve: count_timelines,
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, part_one, part_two};
+    use common::differential;
+
+    #[test]
+    fn enumeration_matches_counting() {
+        let checked = differential::run(
+            differential::seed(),
+            2000,
+            |rng| {
+                let size = rng.range(1..=14) as usize;
+                generate::input(rng, size)
+            },
+            |_| Vec::new(),
+            |input| {
+                let manifold = parse_grid(input).unwrap();
+                let counted = (part_one(&manifold), part_two(&manifold));
+                differential::all_equal(&[
+                    (
+                        "counted",
+                        Some((counted.0.to_string(), counted.1.to_string())),
+                    ),
+                    (
+                        "walked",
+                        count_splits(input)
+                            .unwrap()
+                            .zip(count_timelines(input).unwrap()),
+                    ),
+                ])
+            },
+        );
+        assert!(checked.is_ok(), "{}", checked.unwrap_err());
+    }
+
+    #[test]
+    fn drops_beams_leaving_the_sides() {
+        let input = "S..\n^..\n.^.\n...";
+        assert_eq!(count_splits(input), Ok(Some("2".to_string())));
+        assert_eq!(count_timelines(input), Ok(Some("2".to_string())));
+    }
+
+    #[test]
+    fn gives_up_on_huge_manifolds() {
+        let input = generate::input(&mut common::rng::Rng::new(1), 70);
+        assert!(matches!(count_splits(&input), Ok(Some(_))));
+        assert_eq!(count_timelines(&input), Ok(None));
+    }
+}
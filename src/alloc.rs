@@ -0,0 +1,167 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use common::{Part, Phase};
+
+use crate::days::{self, Day};
+use crate::table::Table;
+
+static ALLOCS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+// The system allocator, counting every allocation it hands out. Installed as
+// the global allocator only with the `alloc-stats` feature.
+pub struct Counting;
+
+fn record(size: usize) {
+    ALLOCS.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size, Ordering::Relaxed);
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            // a realloc counts as a new allocation of the new size
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            record(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AllocStats {
+    pub allocs: usize,
+    pub bytes: usize,
+    // most memory held at once above what was live before the phase
+    pub peak: usize,
+}
+
+// Runs `work` with fresh counters and returns what it allocated.
+fn measure<T>(work: impl FnOnce() -> T) -> (T, AllocStats) {
+    let baseline = CURRENT.load(Ordering::Relaxed);
+    ALLOCS.store(0, Ordering::Relaxed);
+    BYTES.store(0, Ordering::Relaxed);
+    PEAK.store(baseline, Ordering::Relaxed);
+
+    let value = work();
+
+    let stats = AllocStats {
+        allocs: ALLOCS.load(Ordering::Relaxed),
+        bytes: BYTES.load(Ordering::Relaxed),
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(baseline),
+    };
+    (value, stats)
+}
+
+fn profile(day: &Day, input: &str) -> Result<Vec<(Phase, AllocStats)>, String> {
+    let mut results = Vec::new();
+    (day.phases)(input, &Part::ALL, &mut |phase, work| {
+        let (result, stats) = measure(work);
+        results.push((phase, stats));
+        result
+    })
+    .map_err(|err| err.to_string())?;
+
+    Ok(results)
+}
+
+// Reports allocations, bytes allocated and peak memory for parsing and every
+// solver variant of the selected days.
+pub fn report(day: Option<u8>) -> Result<(), String> {
+    let selected: Vec<&Day> = match day {
+        Some(number) => {
+            vec![days::find(number).ok_or(format!("Day {} is not solved yet", number))?]
+        }
+        None => days::DAYS.iter().collect(),
+    };
+
+    let asked_for = day.is_some();
+    let mut table = Table::new(&["Day", "Part", "Variant", "Phase", "Allocs", "Bytes", "Peak"]);
+    for day in selected {
+        // a day asked for by number has to have its input
+        let input = if asked_for {
+            day.read_input()?
+        } else {
+            let Some(input) = day.read_input_if_present()? else {
+                eprintln!("Day {:02}: missing input, skipped", day.number);
+                continue;
+            };
+            input
+        };
+        for (phase, stats) in profile(day, &input)? {
+            let (part, variant, phase) = match phase {
+                Phase::Parse => ("-".to_string(), "-", "parse"),
+                Phase::Solve(part, variant) => (part.number().to_string(), variant, "solve"),
+            };
+            table.add_row(vec![
+                format!("{:02}", day.number),
+                part,
+                variant.to_string(),
+                phase.to_string(),
+                stats.allocs.to_string(),
+                stats.bytes.to_string(),
+                stats.peak.to_string(),
+            ]);
+        }
+    }
+
+    print!("{}", table);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_allocations_in_a_phase() {
+        let (_, stats) = measure(|| {
+            let first = vec![0u8; 1000];
+            drop(first);
+            vec![0u8; 600]
+        });
+
+        // other test threads may allocate concurrently, so only lower bounds
+        assert!(stats.allocs >= 2);
+        assert!(stats.bytes >= 1600);
+        assert!(stats.peak >= 1000);
+    }
+
+    #[test]
+    fn profiles_every_phase_of_a_day() {
+        let day = days::find(1).unwrap();
+        let results = profile(day, "L68\nL30\nR48").unwrap();
+
+        assert_eq!(results[0].0, Phase::Parse);
+        assert!(results[0].1.allocs > 0);
+        assert_eq!(results.len(), 3);
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+
+use common::Part;
+
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+// Recorded answers for the real inputs, one `<day> <part> <answer>` per line.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<(u8, Part), String>);
+
+impl Answers {
+    pub fn parse(content: &str) -> Result<Answers, String> {
+        let mut answers = BTreeMap::new();
+
+        for (idx, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let invalid = || {
+                format!(
+                    "{}:{}: invalid answer line: {}",
+                    ANSWERS_FILE,
+                    idx + 1,
+                    line
+                )
+            };
+
+            let [day, part, answer] = fields[..] else {
+                return Err(invalid());
+            };
+            let day = day.parse::<u8>().map_err(|_| invalid())?;
+            let part = part
+                .parse::<u8>()
+                .ok()
+                .and_then(Part::from_number)
+                .ok_or_else(invalid)?;
+
+            answers.insert((day, part), answer.to_string());
+        }
+
+        Ok(Answers(answers))
+    }
+
+    // A missing file just means nothing has been recorded yet.
+    pub fn load(path: &str) -> Result<Answers, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => Answers::parse(&content),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(format!("Failed to read {}: {}", path, err)),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        fs::write(path, self.to_string())
+            .map_err(|err| format!("Failed to write {}: {}", path, err))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, answer: String) {
+        self.0.insert((day, part), answer);
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# day part answer")?;
+        for ((day, part), answer) in &self.0 {
+            writeln!(f, "{:02} {} {}", day, part.number(), answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_text() {
+        let mut answers = Answers::default();
+        answers.insert(7, Part::Two, "40".to_string());
+        answers.insert(1, Part::One, "3".to_string());
+
+        let text = answers.to_string();
+        assert_eq!(text, "# day part answer\n01 1 3\n07 2 40\n");
+        assert_eq!(Answers::parse(&text), Ok(answers));
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        assert!(Answers::parse("01 3 42").is_err());
+        assert!(Answers::parse("01 1").is_err());
+    }
+}
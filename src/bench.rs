@@ -0,0 +1,410 @@
+use std::time::Duration;
+
+use common::Part;
+use common::bench::{DayBench, Options, Stats};
+
+use crate::days::{self, Day};
+use crate::history::{self, HISTORY_FILE, Record};
+use crate::table::Table;
+
+const DEFAULT_THRESHOLD: f64 = 10.0;
+pub const DEFAULT_SEED: u64 = 1;
+
+#[derive(Debug, PartialEq)]
+pub struct BenchArgs {
+    pub day: Option<u8>,
+    pub parts: Vec<Part>,
+    // only these variants (e.g. default, bit-packed); empty for all of them
+    pub variants: Vec<String>,
+    pub options: Options,
+    // append the medians to bench_history.csv
+    pub save: bool,
+    // compare against the last recorded medians instead of printing stats
+    pub compare: bool,
+    // allowed slowdown in percent before `compare` flags a regression
+    pub threshold: f64,
+    // bench on a generated input of this size instead of input.txt
+    pub size: Option<usize>,
+    pub seed: u64,
+}
+
+fn parse_count(flag: &str, value: Option<&String>, min: usize) -> Result<usize, String> {
+    let value = value.ok_or(format!("Missing value for {}", flag))?;
+    let count = value
+        .parse::<usize>()
+        .map_err(|_| format!("Invalid value for {}: {}", flag, value))?;
+    if count < min {
+        return Err(format!("{} must be at least {}", flag, min));
+    }
+    Ok(count)
+}
+
+pub fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let compare = args.first().is_some_and(|arg| arg == "compare");
+    let mut bench_args = BenchArgs {
+        day: None,
+        parts: Part::ALL.to_vec(),
+        variants: Vec::new(),
+        options: Options::default(),
+        save: false,
+        compare,
+        threshold: DEFAULT_THRESHOLD,
+        size: None,
+        seed: DEFAULT_SEED,
+    };
+    let mut rest = args[usize::from(compare)..].iter();
+
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--save" => bench_args.save = true,
+            "--threshold" => {
+                let value = rest.next().ok_or("Missing value for --threshold")?;
+                bench_args.threshold = value
+                    .parse::<f64>()
+                    .map_err(|_| format!("Invalid value for --threshold: {}", value))?;
+            }
+            "--runs" => bench_args.options.runs = parse_count(arg, rest.next(), 1)?,
+            "--warmup" => bench_args.options.warmup = parse_count(arg, rest.next(), 0)?,
+            "--size" => bench_args.size = Some(parse_count(arg, rest.next(), 1)?),
+            "--seed" => {
+                let value = rest.next().ok_or("Missing value for --seed")?;
+                bench_args.seed = value
+                    .parse::<u64>()
+                    .map_err(|_| format!("Invalid value for --seed: {}", value))?;
+            }
+            "--variant" => {
+                let value = rest.next().ok_or("Missing value for --variant")?;
+                bench_args.variants.push(value.clone());
+            }
+            "--part" => {
+                let value = rest.next().ok_or("Missing value for --part")?;
+                let part = value.parse::<u8>().ok().and_then(Part::from_number);
+                bench_args.parts = vec![part.ok_or(format!("Invalid part: {}", value))?];
+            }
+            day if bench_args.day.is_none() && !day.starts_with("--") => {
+                let number = day.trim_start_matches("day").parse::<u8>();
+                bench_args.day = Some(number.map_err(|_| format!("Invalid day: {}", day))?);
+            }
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    // the history only makes sense for the real inputs
+    if bench_args.size.is_some() && (bench_args.save || bench_args.compare) {
+        return Err("--size can't be combined with --save or compare".to_string());
+    }
+
+    Ok(bench_args)
+}
+
+fn stats_row(day: &Day, part: &str, variant: &str, phase: &str, stats: &Stats) -> Vec<String> {
+    vec![
+        format!("{:02}", day.number),
+        part.to_string(),
+        variant.to_string(),
+        phase.to_string(),
+        format!("{:.2?}", stats.min),
+        format!("{:.2?}", stats.median),
+        format!("{:.2?}", stats.mean),
+        format!("{:.2?}", stats.stddev),
+    ]
+}
+
+#[derive(Debug, PartialEq)]
+enum Change {
+    New,
+    Within(f64),
+    Regressed(f64),
+}
+
+fn classify(previous: Option<Duration>, current: Duration, threshold: f64) -> Change {
+    let Some(previous) = previous.filter(|previous| !previous.is_zero()) else {
+        return Change::New;
+    };
+
+    let percent = (current.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0;
+    if percent > threshold {
+        Change::Regressed(percent)
+    } else {
+        Change::Within(percent)
+    }
+}
+
+fn stats_table(results: &[(&Day, DayBench)]) -> Table {
+    let mut table = Table::new(&[
+        "Day", "Part", "Variant", "Phase", "Min", "Median", "Mean", "Stddev",
+    ]);
+
+    for (day, day_bench) in results {
+        table.add_row(stats_row(day, "-", "-", "parse", &day_bench.parse));
+        for variant in &day_bench.variants {
+            table.add_row(stats_row(
+                day,
+                &variant.part.number().to_string(),
+                variant.variant,
+                "solve",
+                &variant.solve,
+            ));
+        }
+    }
+
+    table
+}
+
+// Returns the comparison table and how many solvers regressed.
+fn compare_table(
+    results: &[(&Day, DayBench)],
+    history: &[Record],
+    threshold: f64,
+) -> (Table, usize) {
+    let latest = history::latest(history);
+    let mut table = Table::new(&[
+        "Day", "Part", "Variant", "Previous", "Commit", "Current", "Change", "Status",
+    ]);
+    let mut regressions = 0;
+
+    for (day, day_bench) in results {
+        for variant in &day_bench.variants {
+            let previous = latest.get(&(day.number, variant.part, variant.variant));
+            let change = classify(
+                previous.map(|record| record.median),
+                variant.solve.median,
+                threshold,
+            );
+
+            let (change, status) = match change {
+                Change::New => ("-".to_string(), "new"),
+                Change::Within(percent) => (format!("{:+.1}%", percent), "ok"),
+                Change::Regressed(percent) => {
+                    regressions += 1;
+                    (format!("{:+.1}%", percent), "REGRESSED")
+                }
+            };
+
+            table.add_row(vec![
+                format!("{:02}", day.number),
+                variant.part.number().to_string(),
+                variant.variant.to_string(),
+                previous.map_or("-".to_string(), |record| format!("{:.2?}", record.median)),
+                previous.map_or("-".to_string(), |record| record.commit.clone()),
+                format!("{:.2?}", variant.solve.median),
+                change,
+                status.to_string(),
+            ]);
+        }
+    }
+
+    (table, regressions)
+}
+
+fn to_records(results: &[(&Day, DayBench)]) -> Vec<Record> {
+    let timestamp = history::now();
+    let commit = history::current_commit();
+
+    results
+        .iter()
+        .flat_map(|(day, day_bench)| {
+            day_bench.variants.iter().map(|variant| Record {
+                timestamp,
+                commit: commit.clone(),
+                day: day.number,
+                part: variant.part,
+                variant: variant.variant.to_string(),
+                median: variant.solve.median,
+            })
+        })
+        .collect()
+}
+
+// Every `--variant` has to name a variant of the selected days and parts;
+// an unknown one would otherwise just leave the table empty.
+fn check_variants(selected: &[&Day], parts: &[Part], names: &[String]) -> Result<(), String> {
+    let mut valid: Vec<&str> = Vec::new();
+    for day in selected {
+        for (part, name) in (day.variants)() {
+            if parts.contains(&part) && !valid.contains(&name) {
+                valid.push(name);
+            }
+        }
+    }
+
+    let unknown: Vec<&str> = names
+        .iter()
+        .map(String::as_str)
+        .filter(|name| !valid.contains(name))
+        .collect();
+    if unknown.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Unknown variant(s): {}. Valid variants: {}",
+            unknown.join(", "),
+            valid.join(", ")
+        ))
+    }
+}
+
+// Repeats parsing and every solver variant against the real inputs and
+// prints min/median/mean/stddev for each, instead of one-shot timings.
+pub fn bench(args: BenchArgs) -> Result<(), String> {
+    let selected: Vec<&Day> = match args.day {
+        Some(number) => {
+            vec![days::find(number).ok_or(format!("Day {} is not solved yet", number))?]
+        }
+        None => days::DAYS.iter().collect(),
+    };
+    check_variants(&selected, &args.parts, &args.variants)?;
+
+    let mut results = Vec::new();
+    for day in selected {
+        let input = match args.size {
+            Some(size) => day.generate_input(args.seed, size)?,
+            // a day asked for by number has to have its input
+            None if args.day.is_some() => day.read_input()?,
+            None => {
+                let Some(input) = day.read_input_if_present()? else {
+                    eprintln!("Day {:02}: missing input, skipped", day.number);
+                    continue;
+                };
+                input
+            }
+        };
+        let day_bench = (day.bench)(&input, &args.parts, &args.variants, args.options)
+            .map_err(|err| err.to_string())?;
+        results.push((day, day_bench));
+    }
+
+    println!(
+        "{} runs after {} warmup run(s)",
+        args.options.runs, args.options.warmup
+    );
+    if let Some(size) = args.size {
+        println!("on generated inputs of size {} (seed {})", size, args.seed);
+    }
+
+    let mut regressions = 0;
+    if args.compare {
+        let history = history::load(HISTORY_FILE)?;
+        let (table, count) = compare_table(&results, &history, args.threshold);
+        print!("{}", table);
+        regressions = count;
+    } else {
+        print!("{}", stats_table(&results));
+    }
+
+    if args.save {
+        history::append(HISTORY_FILE, &to_records(&results))?;
+        println!("Saved medians to {}", HISTORY_FILE);
+    }
+
+    if regressions > 0 {
+        return Err(format!(
+            "{} solver(s) slowed down by more than {}%",
+            regressions, args.threshold
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn defaults_to_every_day_and_part() {
+        assert_eq!(
+            parse_bench_args(&[]),
+            Ok(BenchArgs {
+                day: None,
+                parts: Part::ALL.to_vec(),
+                variants: Vec::new(),
+                options: Options::default(),
+                save: false,
+                compare: false,
+                threshold: DEFAULT_THRESHOLD,
+                size: None,
+                seed: DEFAULT_SEED,
+            })
+        );
+    }
+
+    #[test]
+    fn parses_compare_mode() {
+        let bench_args =
+            parse_bench_args(&args(&["compare", "2", "--threshold", "25", "--save"])).unwrap();
+        assert!(bench_args.compare && bench_args.save);
+        assert_eq!(bench_args.day, Some(2));
+        assert_eq!(bench_args.threshold, 25.0);
+    }
+
+    #[test]
+    fn flags_slowdowns_over_the_threshold() {
+        let ms = Duration::from_millis;
+        assert_eq!(classify(None, ms(10), 10.0), Change::New);
+        assert!(matches!(
+            classify(Some(ms(10)), ms(8), 10.0),
+            Change::Within(percent) if (percent + 20.0).abs() < 1e-9
+        ));
+        assert!(matches!(
+            classify(Some(ms(10)), ms(12), 10.0),
+            Change::Regressed(percent) if (percent - 20.0).abs() < 1e-9
+        ));
+    }
+
+    #[test]
+    fn parses_day_and_counts() {
+        let bench_args = parse_bench_args(&args(&["4", "--runs", "50", "--warmup", "3"])).unwrap();
+        assert_eq!(bench_args.day, Some(4));
+        assert_eq!(bench_args.options.runs, 50);
+        assert_eq!(bench_args.options.warmup, 3);
+    }
+
+    #[test]
+    fn parses_generated_input_size() {
+        let bench_args = parse_bench_args(&args(&["7", "--size", "500", "--seed", "9"])).unwrap();
+        assert_eq!((bench_args.size, bench_args.seed), (Some(500), 9));
+        assert!(parse_bench_args(&args(&["--size", "500", "--save"])).is_err());
+    }
+
+    #[test]
+    fn parses_variant_selection() {
+        let bench_args = parse_bench_args(&args(&[
+            "4",
+            "--variant",
+            "default",
+            "--variant",
+            "bit-packed",
+        ]))
+        .unwrap();
+        assert_eq!(bench_args.variants, ["default", "bit-packed"]);
+        assert!(parse_bench_args(&args(&["--variant"])).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_counts() {
+        assert!(parse_bench_args(&args(&["--runs", "many"])).is_err());
+        assert_eq!(
+            parse_bench_args(&args(&["--runs", "0"])),
+            Err("--runs must be at least 1".to_string())
+        );
+        assert!(parse_bench_args(&args(&["--warmup", "0"])).is_ok());
+    }
+
+    #[test]
+    fn rejects_unknown_variants() {
+        let day04 = [days::find(4).unwrap()];
+        assert!(check_variants(&day04, &Part::ALL, &args(&["bit-packed", "serial"])).is_ok());
+        assert_eq!(
+            check_variants(&day04, &[Part::One], &args(&["serial"])),
+            Err(
+                "Unknown variant(s): serial. Valid variants: default, thread-per-row, bit-packed"
+                    .to_string()
+            )
+        );
+    }
+}
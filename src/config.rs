@@ -0,0 +1,109 @@
+use std::fs;
+
+pub const CONFIG_FILE: &str = "aoc2025.conf";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2025";
+const SESSION_VAR: &str = "AOC_SESSION";
+const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+// Settings for talking to the Advent of Code site, read from `aoc2025.conf`
+// (`key = value` lines) and overridden by AOC_SESSION / AOC_BASE_URL.
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+}
+
+impl Config {
+    pub fn parse(content: &str) -> Result<Config, String> {
+        let mut config = Config::default();
+
+        for (idx, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || format!("{}:{}: invalid setting: {}", CONFIG_FILE, idx + 1, line);
+            let (key, value) = line.split_once('=').ok_or_else(invalid)?;
+            let value = value.trim().to_string();
+            match key.trim() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                _ => return Err(invalid()),
+            }
+        }
+
+        Ok(config)
+    }
+
+    // Environment variables win over the file, which is optional.
+    pub fn load(path: &str, env: impl Fn(&str) -> Option<String>) -> Result<Config, String> {
+        let mut config = match fs::read_to_string(path) {
+            Ok(content) => Config::parse(&content)?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Config::default(),
+            Err(err) => return Err(format!("Failed to read {}: {}", path, err)),
+        };
+
+        if let Some(session) = env(SESSION_VAR) {
+            config.session = Some(session);
+        }
+        if let Some(base_url) = env(BASE_URL_VAR) {
+            config.base_url = base_url;
+        }
+
+        Ok(config)
+    }
+
+    pub fn session(&self) -> Result<&str, String> {
+        self.session
+            .as_deref()
+            .filter(|s| !s.is_empty())
+            .ok_or(format!(
+                "Missing session token: set {} or `session = ...` in {}",
+                SESSION_VAR, CONFIG_FILE
+            ))
+    }
+
+    pub fn day_url(&self, day: u8) -> String {
+        format!("{}/day/{}", self.base_url.trim_end_matches('/'), day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_settings() {
+        let config =
+            Config::parse("# comment\nsession = abc123\nbase_url = http://127.0.0.1:8080/\n");
+        assert_eq!(
+            config,
+            Ok(Config {
+                session: Some("abc123".to_string()),
+                base_url: "http://127.0.0.1:8080/".to_string(),
+            })
+        );
+        assert_eq!(config.unwrap().day_url(4), "http://127.0.0.1:8080/day/4");
+        assert!(Config::parse("token abc").is_err());
+    }
+
+    #[test]
+    fn environment_overrides_the_file() {
+        let env = |name: &str| (name == SESSION_VAR).then(|| "from-env".to_string());
+        let config = Config::load("missing.conf", env).unwrap();
+
+        assert_eq!(config.session(), Ok("from-env"));
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+        assert!(Config::default().session().is_err());
+    }
+}
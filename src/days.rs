@@ -0,0 +1,125 @@
+use std::time::Instant;
+
+use common::bench::{self, DayBench, Options};
+use common::input::{self, InputError, Source};
+use common::rng::Rng;
+use common::{DayRun, Error, Part, ReferenceAnswers, Solution, run, run_references, variants};
+
+// input, parts, variant names (empty for all), options
+type BenchFn = fn(&str, &[Part], &[String], Options) -> Result<DayBench, Error>;
+
+pub struct Day {
+    pub number: u8,
+    pub run: fn(&str, &[Part]) -> Result<DayRun, Error>,
+    pub bench: BenchFn,
+    pub generate: fn(&mut Rng, usize) -> Option<String>,
+    pub references: fn(&str) -> ReferenceAnswers,
+    pub variants: fn() -> Vec<(Part, &'static str)>,
+    #[cfg(feature = "alloc-stats")]
+    pub phases: fn(&str, &[Part], &mut common::Probe) -> Result<(), Error>,
+}
+
+fn variant_names<S: Solution>() -> Vec<(Part, &'static str)> {
+    variants::<S>()
+        .iter()
+        .map(|variant| (variant.part, variant.name))
+        .collect()
+}
+
+const fn day<S: Solution>() -> Day {
+    Day {
+        number: S::DAY,
+        run: run::<S>,
+        bench: bench::bench::<S>,
+        generate: S::generate,
+        references: run_references::<S>,
+        variants: variant_names::<S>,
+        #[cfg(feature = "alloc-stats")]
+        phases: common::run_phases::<S>,
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    day::<day01::Day01>(),
+    day::<day02::Day02>(),
+    day::<day03::Day03>(),
+    day::<day04::Day04>(),
+    day::<day05::Day05>(),
+    day::<day06::Day06>(),
+    day::<day07::Day07>(),
+];
+
+impl Day {
+    pub fn read_input(&self) -> Result<String, String> {
+        input::load(self.number, &Source::Default).map_err(|err| err.to_string())
+    }
+
+    // Like `read_input`, but None when dayNN/input.txt doesn't exist yet (a
+    // day just created with `new`), so commands over every day can skip it.
+    pub fn read_input_if_present(&self) -> Result<Option<String>, String> {
+        match input::load(self.number, &Source::Default) {
+            Ok(input) => Ok(Some(input)),
+            Err(InputError::NotFound(_)) => Ok(None),
+            Err(err) => Err(err.to_string()),
+        }
+    }
+
+    // Reads the input and solves the requested parts, timing every phase.
+    pub fn run_source(&self, source: &Source, parts: &[Part]) -> Result<DayRun, String> {
+        let start = Instant::now();
+        let input = input::load(self.number, source).map_err(|err| err.to_string())?;
+        let read = start.elapsed();
+
+        let day_run = (self.run)(&input, parts).map_err(|err| err.to_string())?;
+        Ok(DayRun { read, ..day_run })
+    }
+
+    // A random input of the given size; the same seed gives the same input.
+    // Size 0 is rejected: several days can't express an empty input.
+    pub fn generate_input(&self, seed: u64, size: usize) -> Result<String, String> {
+        if size == 0 {
+            return Err("Input size must be at least 1".to_string());
+        }
+        (self.generate)(&mut Rng::new(seed), size)
+            .ok_or(format!("Day {} has no input generator", self.number))
+    }
+}
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_registered_in_order() {
+        // `aoc2025 new` may skip days, but keeps the list sorted
+        assert!(DAYS.windows(2).all(|pair| pair[0].number < pair[1].number));
+    }
+
+    #[test]
+    fn generated_inputs_solve() {
+        // days scaffolded by `aoc2025 new` have no generator yet
+        for day in DAYS
+            .iter()
+            .filter(|day| (day.generate)(&mut Rng::new(1), 1).is_some())
+        {
+            let input = day.generate_input(1, 20).unwrap();
+            assert!((day.run)(&input, &Part::ALL).is_ok(), "day {}", day.number);
+        }
+    }
+
+    #[test]
+    fn rejects_empty_generated_inputs() {
+        assert!(find(2).unwrap().generate_input(1, 0).is_err());
+        assert!(find(2).unwrap().generate_input(1, 1).is_ok());
+    }
+
+    #[test]
+    fn finds_registered_day() {
+        assert!(find(7).is_some());
+        assert!(find(26).is_none());
+    }
+}
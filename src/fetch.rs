@@ -0,0 +1,87 @@
+use std::fs;
+use std::path::Path;
+
+use crate::config::Config;
+use crate::http;
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Downloaded,
+    // the input was already on disk, so nothing was requested
+    Cached,
+}
+
+// Downloads a day's input to `path` unless it's already there. Inputs never
+// change, so an existing non-empty file is never re-downloaded.
+pub fn fetch(path: &Path, day: u8, config: &Config) -> Result<Fetched, String> {
+    if fs::metadata(path).is_ok_and(|meta| meta.len() > 0) {
+        return Ok(Fetched::Cached);
+    }
+
+    let url = format!("{}/input", config.day_url(day));
+    let response = http::get(&url, config.session()?)?;
+    if response.status != 200 {
+        return Err(format!(
+            "Failed to fetch {}: HTTP {}: {}",
+            url,
+            response.status,
+            response.body.trim()
+        ));
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("Failed to create {}: {}", parent.display(), err))?;
+    }
+    fs::write(path, response.body)
+        .map_err(|err| format!("Failed to write {}: {}", path.display(), err))?;
+
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::stub;
+
+    fn config(base_url: &str) -> Config {
+        Config {
+            session: Some("abc123".to_string()),
+            base_url: base_url.to_string(),
+        }
+    }
+
+    #[test]
+    fn downloads_once_then_uses_the_cache() {
+        let path =
+            std::env::temp_dir().join(format!("aoc2025_fetch_{}/input.txt", std::process::id()));
+        let (url, server) = stub::serve_once("200 OK", "L68\nL30\n");
+
+        let first = fetch(&path, 1, &config(&url));
+        let request = server.join().unwrap();
+        // the stub only answers once, so a second request would fail
+        let second = fetch(&path, 1, &config(&url));
+        let content = fs::read_to_string(&path).unwrap();
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+        assert_eq!(first, Ok(Fetched::Downloaded));
+        assert_eq!(second, Ok(Fetched::Cached));
+        assert_eq!(content, "L68\nL30\n");
+        assert!(request.starts_with("GET /day/1/input "));
+        assert!(request.contains("Cookie: session=abc123"));
+    }
+
+    #[test]
+    fn reports_http_errors_without_writing() {
+        let path =
+            std::env::temp_dir().join(format!("aoc2025_fetch_err_{}.txt", std::process::id()));
+        let (url, server) =
+            stub::serve_once("400 Bad Request", "Please log in to get your puzzle input.");
+
+        let result = fetch(&path, 2, &config(&url));
+        server.join().unwrap();
+
+        assert!(result.unwrap_err().contains("HTTP 400: Please log in"));
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use common::Part;
+
+pub const HISTORY_FILE: &str = "bench_history.csv";
+const HEADER: &str = "timestamp,commit,day,part,variant,median_ns";
+
+// One benchmarked solver median, as stored in `bench_history.csv`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub timestamp: u64,
+    pub commit: String,
+    pub day: u8,
+    pub part: Part,
+    pub variant: String,
+    pub median: Duration,
+}
+
+impl Record {
+    fn parse(line: &str) -> Option<Record> {
+        let fields: Vec<&str> = line.split(',').collect();
+        let [timestamp, commit, day, part, variant, median] = fields[..] else {
+            return None;
+        };
+
+        Some(Record {
+            timestamp: timestamp.parse().ok()?,
+            commit: commit.to_string(),
+            day: day.parse().ok()?,
+            part: Part::from_number(part.parse().ok()?)?,
+            variant: variant.to_string(),
+            median: Duration::from_nanos(median.parse().ok()?),
+        })
+    }
+
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{}",
+            self.timestamp,
+            self.commit,
+            self.day,
+            self.part.number(),
+            self.variant,
+            self.median.as_nanos()
+        )
+    }
+}
+
+pub fn parse(content: &str) -> Result<Vec<Record>, String> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && *line != HEADER)
+        .map(|(idx, line)| {
+            Record::parse(line).ok_or(format!(
+                "{}:{}: invalid record: {}",
+                HISTORY_FILE,
+                idx + 1,
+                line
+            ))
+        })
+        .collect()
+}
+
+pub fn load(path: &str) -> Result<Vec<Record>, String> {
+    match fs::read_to_string(path) {
+        Ok(content) => parse(&content),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(format!("Failed to read {}: {}", path, err)),
+    }
+}
+
+pub fn append(path: &str, records: &[Record]) -> Result<(), String> {
+    let is_new = fs::metadata(path).is_err();
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|err| format!("Failed to open {}: {}", path, err))?;
+
+    let mut content = String::new();
+    if is_new {
+        content.push_str(HEADER);
+        content.push('\n');
+    }
+    for record in records {
+        content.push_str(&record.to_csv());
+        content.push('\n');
+    }
+
+    file.write_all(content.as_bytes())
+        .map_err(|err| format!("Failed to write {}: {}", path, err))
+}
+
+// The most recent record of every (day, part, variant).
+pub fn latest(records: &[Record]) -> HashMap<(u8, Part, &str), &Record> {
+    let mut latest = HashMap::new();
+    for record in records {
+        // records are appended in order, so later lines win
+        latest.insert((record.day, record.part, record.variant.as_str()), record);
+    }
+    latest
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+// Short hash of HEAD in the local repo, marked `-dirty` with uncommitted
+// changes to tracked files. Untracked files don't count, so bench_history.csv
+// itself (ignored, it's per machine) never marks a clean checkout dirty.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash)
+            if git(&["status", "--porcelain", "--untracked-files=no"])
+                .is_some_and(|s| !s.is_empty()) =>
+        {
+            format!("{}-dirty", hash)
+        }
+        Some(hash) => hash,
+        None => "unknown".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(timestamp: u64, variant: &str, median_ms: u64) -> Record {
+        Record {
+            timestamp,
+            commit: "abc1234".to_string(),
+            day: 4,
+            part: Part::One,
+            variant: variant.to_string(),
+            median: Duration::from_millis(median_ms),
+        }
+    }
+
+    #[test]
+    fn round_trips_through_csv() {
+        let records = vec![record(1, "default", 5), record(1, "thread-per-row", 3)];
+        let csv = format!(
+            "{}\n{}\n{}\n",
+            HEADER,
+            records[0].to_csv(),
+            records[1].to_csv()
+        );
+
+        assert_eq!(parse(&csv), Ok(records));
+    }
+
+    #[test]
+    fn keeps_only_the_latest_record() {
+        let records = vec![record(1, "default", 5), record(2, "default", 7)];
+        let latest = latest(&records);
+
+        assert_eq!(latest.len(), 1);
+        assert_eq!(latest[&(4, Part::One, "default")].timestamp, 2);
+    }
+
+    #[test]
+    fn rejects_malformed_records() {
+        assert!(parse("1,abc,4,3,default,100").is_err());
+    }
+}
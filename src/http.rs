@@ -0,0 +1,267 @@
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+const USER_AGENT: &str = "github.com/frontendwizard/advent-of-code-2025 (aoc2025 runner)";
+// so a stalled server can't hang the CLI
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+// Minimal requests with a session cookie. Plain `http://` URLs (like a local
+// stub server) are spoken directly over a TcpStream; `https://` goes through
+// curl since std has no TLS.
+pub fn get(url: &str, session: &str) -> Result<Response, String> {
+    send(url, session, None)
+}
+
+// POSTs an already url-encoded form body.
+pub fn post(url: &str, session: &str, form: &str) -> Result<Response, String> {
+    send(url, session, Some(form))
+}
+
+fn send(url: &str, session: &str, form: Option<&str>) -> Result<Response, String> {
+    if let Some(rest) = url.strip_prefix("http://") {
+        plain_send(rest, session, form)
+    } else if url.starts_with("https://") {
+        curl_send(url, session, form)
+    } else {
+        Err(format!("Unsupported URL: {}", url))
+    }
+}
+
+fn plain_send(host_and_path: &str, session: &str, form: Option<&str>) -> Result<Response, String> {
+    let (host, path) = match host_and_path.find('/') {
+        Some(idx) => host_and_path.split_at(idx),
+        None => (host_and_path, "/"),
+    };
+    let address = if host.contains(':') {
+        host.to_string()
+    } else {
+        format!("{}:80", host)
+    };
+
+    let mut stream =
+        connect(&address).map_err(|err| format!("Failed to connect to {}: {}", host, err))?;
+    // HTTP/1.0 keeps the response un-chunked and closes the connection after it
+    let mut request = format!(
+        "{} {} HTTP/1.0\r\nHost: {}\r\nUser-Agent: {}\r\nCookie: session={}\r\n",
+        if form.is_some() { "POST" } else { "GET" },
+        path,
+        host,
+        USER_AGENT,
+        session
+    );
+    match form {
+        Some(form) => request.push_str(&format!(
+            "Content-Type: application/x-www-form-urlencoded\r\nContent-Length: {}\r\n\r\n{}",
+            form.len(),
+            form
+        )),
+        None => request.push_str("\r\n"),
+    }
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|err| format!("Failed to send request to {}: {}", host, err))?;
+
+    let mut raw = String::new();
+    stream
+        .read_to_string(&mut raw)
+        .map_err(|err| format!("Failed to read response from {}: {}", host, err))?;
+
+    parse_response(&raw)
+}
+
+// Tries every address `address` resolves to, with timeouts on connecting,
+// reading and writing.
+fn connect(address: &str) -> std::io::Result<TcpStream> {
+    let mut last_err = None;
+    for socket_address in address.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&socket_address, TIMEOUT) {
+            Ok(stream) => {
+                stream.set_read_timeout(Some(TIMEOUT))?;
+                stream.set_write_timeout(Some(TIMEOUT))?;
+                return Ok(stream);
+            }
+            Err(err) => last_err = Some(err),
+        }
+    }
+
+    Err(last_err
+        .unwrap_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "no addresses found")))
+}
+
+fn parse_response(raw: &str) -> Result<Response, String> {
+    let (head, body) = raw
+        .split_once("\r\n\r\n")
+        .ok_or("Malformed HTTP response")?;
+    let status = head
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or(format!("Malformed HTTP status line: {}", head))?;
+
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+// curl reads the cookie from a config on stdin, so the session token never
+// shows up in its command line (and in `ps` for every local user).
+fn curl_config(session: &str) -> String {
+    format!(
+        "cookie = \"session={}\"\n",
+        session.replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
+fn curl_send(url: &str, session: &str, form: Option<&str>) -> Result<Response, String> {
+    let mut curl = Command::new("curl");
+    curl.args(["--silent", "--show-error", "--write-out", "\n%{http_code}"])
+        .args(["--user-agent", USER_AGENT])
+        .args(["--connect-timeout", &TIMEOUT.as_secs().to_string()])
+        .args(["--max-time", &(TIMEOUT.as_secs() * 2).to_string()])
+        .args(["--config", "-"]);
+    if let Some(form) = form {
+        curl.args(["--data-raw", form]);
+    }
+    let mut child = curl
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("Failed to run curl: {}", err))?;
+    child
+        .stdin
+        .take()
+        .ok_or("Failed to open curl's stdin")?
+        .write_all(curl_config(session).as_bytes())
+        .map_err(|err| format!("Failed to pass the session to curl: {}", err))?;
+    let output = child
+        .wait_with_output()
+        .map_err(|err| format!("Failed to run curl: {}", err))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "curl failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    // --write-out appends the status code on its own line
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = stdout.rsplit_once('\n').ok_or("Malformed curl output")?;
+    let status = status
+        .parse::<u16>()
+        .map_err(|_| format!("Malformed curl status: {}", status))?;
+
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+// A one-shot HTTP server for tests: serves `response` to the first client
+// and hands back the raw request it received.
+#[cfg(test)]
+pub mod stub {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    pub fn serve_once(status: &str, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!("HTTP/1.1 {}\r\nConnection: close\r\n\r\n{}", status, body);
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            // read until the end of the headers plus any Content-Length body
+            loop {
+                let read = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..read]);
+                let text = String::from_utf8_lossy(&request);
+                if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                    let length = head
+                        .lines()
+                        .find_map(|line| line.strip_prefix("Content-Length: "))
+                        .and_then(|length| length.parse::<usize>().ok())
+                        .unwrap_or(0);
+                    if body.len() >= length {
+                        break;
+                    }
+                }
+                if read == 0 {
+                    break;
+                }
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        (url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_status_and_body() {
+        let raw = "HTTP/1.1 404 Not Found\r\nContent-Type: text/plain\r\n\r\nnope\n";
+        assert_eq!(
+            parse_response(raw),
+            Ok(Response {
+                status: 404,
+                body: "nope\n".to_string()
+            })
+        );
+        assert!(parse_response("garbage").is_err());
+    }
+
+    #[test]
+    fn gets_from_plain_http_server() {
+        let (url, server) = stub::serve_once("200 OK", "hello");
+        let response = get(&format!("{}/day/1/input", url), "abc").unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "hello");
+        assert!(request.starts_with("GET /day/1/input HTTP/1.0\r\n"));
+        assert!(request.contains("Cookie: session=abc\r\n"));
+    }
+
+    #[test]
+    fn posts_form_bodies() {
+        let (url, server) = stub::serve_once("200 OK", "thanks");
+        let response = post(&format!("{}/day/1/answer", url), "abc", "level=1&answer=3").unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(response.body, "thanks");
+        assert!(request.starts_with("POST /day/1/answer HTTP/1.0\r\n"));
+        assert!(request.contains("Content-Length: 16\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=1&answer=3"));
+    }
+
+    #[test]
+    fn quotes_session_for_curl_config() {
+        assert_eq!(curl_config("abc123"), "cookie = \"session=abc123\"\n");
+        assert_eq!(curl_config("a\"b\\c"), "cookie = \"session=a\\\"b\\\\c\"\n");
+    }
+
+    #[test]
+    fn rejects_unknown_schemes() {
+        assert!(get("ftp://example.com", "abc").is_err());
+    }
+}
@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use common::PartRun;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    // one JSON object per line, for scripts
+    Json,
+}
+
+impl Format {
+    pub fn parse(value: &str) -> Result<Format, String> {
+        match value {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Invalid format: {}", value)),
+        }
+    }
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// {"day":7,"part":1,"answer":"1628","variant":"default","parse_ns":..,"solve_ns":..}
+pub fn part_record(day: u8, part_run: &PartRun, variant: &str, parse: Duration) -> String {
+    format!(
+        "{{\"day\":{},\"part\":{},\"answer\":\"{}\",\"variant\":\"{}\",\"parse_ns\":{},\"solve_ns\":{}}}",
+        day,
+        part_run.part.number(),
+        escape(&part_run.answer),
+        escape(variant),
+        parse.as_nanos(),
+        part_run.solve.as_nanos()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Part;
+
+    #[test]
+    fn formats_one_object_per_part() {
+        let part_run = PartRun {
+            part: Part::Two,
+            answer: "27055852018812".to_string(),
+            solve: Duration::from_nanos(1500),
+        };
+
+        assert_eq!(
+            part_record(7, &part_run, "default", Duration::from_nanos(42)),
+            r#"{"day":7,"part":2,"answer":"27055852018812","variant":"default","parse_ns":42,"solve_ns":1500}"#
+        );
+    }
+
+    #[test]
+    fn escapes_strings() {
+        assert_eq!(escape("a\"b\\c\nd\u{1}"), "a\\\"b\\\\c\\nd\\u0001");
+    }
+}
@@ -1,3 +1,462 @@
+use std::path::Path;
+use std::process;
+use std::time::Instant;
+
+use common::input::Source;
+use common::{DEFAULT_VARIANT, DayRun, Part};
+
+use answers::{ANSWERS_FILE, Answers};
+use config::{CONFIG_FILE, Config};
+use fetch::Fetched;
+use json::Format;
+use submit::{Outcome, SUBMISSIONS_FILE};
+use table::Table;
+
+#[cfg(feature = "alloc-stats")]
+mod alloc;
+mod answers;
+mod bench;
+mod config;
+mod days;
+mod fetch;
+mod history;
+mod http;
+mod json;
+mod progress;
+mod scaffold;
+mod submit;
+mod table;
+mod test_examples;
+mod verify;
+mod watch;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: alloc::Counting = alloc::Counting;
+
+const USAGE: &str = "Usage:
+  aoc2025 run <day> [--part 1|2] [--input <path>|-] [--example <name>] [--format text|json]
+  aoc2025 all [--format text|json]
+  aoc2025 verify [--record | --reference]   (--reference needs --features reference)
+  aoc2025 test-examples [<day>]
+  aoc2025 watch <day>
+  aoc2025 bench [<day>] [--part 1|2] [--variant <name>]... [--runs <n>] [--warmup <n>] [--save | --size <n> [--seed <n>]]
+  aoc2025 bench compare [<day>] [--threshold <percent>] [--runs <n>] [--warmup <n>] [--save]
+  aoc2025 generate <day> --size <n> [--seed <n>]
+  aoc2025 alloc [<day>]   (needs --features alloc-stats)
+  aoc2025 new <day>
+  aoc2025 readme
+  aoc2025 fetch <day>
+  aoc2025 submit <day> <part>";
+
+#[derive(Debug, PartialEq)]
+struct RunArgs {
+    day: u8,
+    part: Option<Part>,
+    input: Source,
+    format: Format,
+}
+
+// Accepts `7` as well as `day07`.
+fn parse_day(arg: &str) -> Result<u8, String> {
+    arg.trim_start_matches("day")
+        .parse::<u8>()
+        .map_err(|_| format!("Invalid day: {}", arg))
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let day = parse_day(args.first().ok_or("Missing day")?)?;
+
+    let mut part = None;
+    let mut input = Source::Default;
+    let mut format = Format::Text;
+    let mut rest = args[1..].iter();
+
+    while let Some(flag) = rest.next() {
+        match flag.as_str() {
+            "--part" => {
+                let value = rest.next().ok_or("Missing value for --part")?;
+                let number = value.parse::<u8>().ok();
+                part = Some(
+                    number
+                        .and_then(Part::from_number)
+                        .ok_or(format!("Invalid part: {}", value))?,
+                );
+            }
+            "--input" => {
+                let value = rest.next().ok_or("Missing value for --input")?;
+                input = Source::from_arg(Some(value));
+            }
+            "--format" => {
+                format = Format::parse(rest.next().ok_or("Missing value for --format")?)?;
+            }
+            "--example" => {
+                let value = rest.next().ok_or("Missing value for --example")?;
+                input = Source::Example(value.clone());
+            }
+            _ => return Err(format!("Unknown argument: {}", flag)),
+        }
+    }
+
+    Ok(RunArgs {
+        day,
+        part,
+        input,
+        format,
+    })
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let day = days::find(args.day).ok_or(format!("Day {} is not solved yet", args.day))?;
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let day_run = day.run_source(&args.input, &parts)?;
+
+    for part_run in &day_run.parts {
+        match args.format {
+            Format::Text => println!(
+                "Day {:02} part {}: {}",
+                day.number,
+                part_run.part.number(),
+                part_run.answer
+            ),
+            Format::Json => println!(
+                "{}",
+                json::part_record(day.number, part_run, DEFAULT_VARIANT, day_run.parse)
+            ),
+        }
+    }
+
+    if args.format == Format::Text {
+        println!("{}", timings(&day_run));
+    }
+
+    Ok(())
+}
+
+// "read 12µs | parse 40µs | part 1 1.2ms | part 2 3.4ms"
+fn timings(day_run: &DayRun) -> String {
+    let mut spans = vec![
+        format!("read {:.2?}", day_run.read),
+        format!("parse {:.2?}", day_run.parse),
+    ];
+    for part_run in &day_run.parts {
+        spans.push(format!(
+            "part {} {:.2?}",
+            part_run.part.number(),
+            part_run.solve
+        ));
+    }
+    spans.join(" | ")
+}
+
+fn run_all(format: Format) -> Result<(), String> {
+    let mut table = Table::new(&["Day", "Part", "Answer", "Read", "Parse", "Solve"]);
+
+    for day in days::DAYS {
+        let start = Instant::now();
+        let Some(input) = day.read_input_if_present()? else {
+            if format == Format::Json {
+                eprintln!("Day {:02}: missing input", day.number);
+            } else {
+                table.add_row(vec![
+                    format!("{:02}", day.number),
+                    "-".to_string(),
+                    "missing input".to_string(),
+                    "-".to_string(),
+                    "-".to_string(),
+                    "-".to_string(),
+                ]);
+            }
+            continue;
+        };
+        let read = start.elapsed();
+        let day_run = (day.run)(&input, &Part::ALL).map_err(|err| err.to_string())?;
+
+        for part_run in day_run.parts {
+            if format == Format::Json {
+                println!(
+                    "{}",
+                    json::part_record(day.number, &part_run, DEFAULT_VARIANT, day_run.parse)
+                );
+                continue;
+            }
+            table.add_row(vec![
+                format!("{:02}", day.number),
+                part_run.part.number().to_string(),
+                part_run.answer,
+                format!("{:.2?}", read),
+                format!("{:.2?}", day_run.parse),
+                format!("{:.2?}", part_run.solve),
+            ]);
+        }
+    }
+
+    if format == Format::Text {
+        print!("{}", table);
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, PartialEq)]
+struct GenerateArgs {
+    day: u8,
+    size: usize,
+    seed: u64,
+}
+
+fn parse_generate_args(args: &[String]) -> Result<GenerateArgs, String> {
+    let day = parse_day(args.first().ok_or("Missing day")?)?;
+
+    let mut size = None;
+    let mut seed = bench::DEFAULT_SEED;
+    let mut rest = args[1..].iter();
+
+    while let Some(flag) = rest.next() {
+        let value = rest.next().ok_or(format!("Missing value for {}", flag))?;
+        let invalid = || format!("Invalid value for {}: {}", flag, value);
+        match flag.as_str() {
+            "--size" => size = Some(value.parse::<usize>().map_err(|_| invalid())?),
+            "--seed" => seed = value.parse::<u64>().map_err(|_| invalid())?,
+            _ => return Err(format!("Unknown argument: {}", flag)),
+        }
+    }
+
+    Ok(GenerateArgs {
+        day,
+        size: size.ok_or("Missing --size")?,
+        seed,
+    })
+}
+
+// Prints a random input, e.g. to redirect into a file for `run --input`.
+fn generate(args: GenerateArgs) -> Result<(), String> {
+    let day = days::find(args.day).ok_or(format!("Day {} is not solved yet", args.day))?;
+    println!("{}", day.generate_input(args.seed, args.size)?);
+    Ok(())
+}
+
+fn new_day(arg: &str) -> Result<(), String> {
+    let day = parse_day(arg)?;
+
+    scaffold::new_day(Path::new("."), day)?;
+
+    println!(
+        "Created day{:02}, registered in Cargo.toml and src/days.rs",
+        day
+    );
+    println!(
+        "Next: paste the puzzle into day{:02}/README.md, note the example",
+        day
+    );
+    println!(
+        "answers in day{:02}/examples.txt and run `cargo test -p day{:02}`",
+        day, day
+    );
+
+    Ok(())
+}
+
+fn fetch_input(arg: &str) -> Result<(), String> {
+    let day = parse_day(arg)?;
+    let config = Config::load(CONFIG_FILE, |name| std::env::var(name).ok())?;
+    let path = Source::Default.path(day).ok_or("No default input path")?;
+
+    match fetch::fetch(&path, day, &config)? {
+        Fetched::Downloaded => println!("Downloaded {}", path.display()),
+        Fetched::Cached => println!("{} already exists, not downloading", path.display()),
+    }
+
+    Ok(())
+}
+
+fn submit_answer(day: &str, part: &str) -> Result<(), String> {
+    let day = days::find(parse_day(day)?).ok_or(format!("Day {} is not solved yet", day))?;
+    let part = part
+        .parse::<u8>()
+        .ok()
+        .and_then(Part::from_number)
+        .ok_or(format!("Invalid part: {}", part))?;
+    let config = Config::load(CONFIG_FILE, |name| std::env::var(name).ok())?;
+
+    let input = day.read_input()?;
+    let day_run = (day.run)(&input, &[part]).map_err(|err| err.to_string())?;
+    let answer = &day_run.parts[0].answer;
+    println!(
+        "Submitting day {:02} part {}: {}",
+        day.number,
+        part.number(),
+        answer
+    );
+
+    let record = submit::submit(
+        SUBMISSIONS_FILE,
+        &config,
+        day.number,
+        part,
+        answer,
+        history::now(),
+    )?;
+    if record.retry_at > record.timestamp {
+        println!("Cooldown: {}s", record.retry_at - record.timestamp);
+    }
+
+    match record.outcome {
+        Outcome::Correct => {
+            let mut answers = Answers::load(ANSWERS_FILE)?;
+            answers.insert(day.number, part, answer.clone());
+            answers.save(ANSWERS_FILE)?;
+            println!("Correct! Recorded in {}", ANSWERS_FILE);
+            Ok(())
+        }
+        outcome => Err(format!("Not accepted: {}", outcome)),
+    }
+}
+
 fn main() {
-    println!("Hello, world!");
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => parse_run_args(&args[1..]).and_then(run),
+        Some("all") => match &args[1..] {
+            [] => run_all(Format::Text),
+            [flag, value] if flag == "--format" => Format::parse(value).and_then(run_all),
+            _ => Err(USAGE.to_string()),
+        },
+        Some("bench") => bench::parse_bench_args(&args[1..]).and_then(bench::bench),
+        Some("test-examples") => match &args[1..] {
+            [] => test_examples::test_examples(None),
+            [day] => parse_day(day).and_then(|day| test_examples::test_examples(Some(day))),
+            _ => Err(USAGE.to_string()),
+        },
+        Some("watch") => match &args[1..] {
+            [day] => parse_day(day).and_then(watch::watch),
+            _ => Err(USAGE.to_string()),
+        },
+        Some("readme") if args.len() == 1 => progress::update_readme(),
+        Some("fetch") => match &args[1..] {
+            [day] => fetch_input(day),
+            _ => Err(USAGE.to_string()),
+        },
+        Some("submit") => match &args[1..] {
+            [day, part] => submit_answer(day, part),
+            _ => Err(USAGE.to_string()),
+        },
+        Some("generate") => parse_generate_args(&args[1..]).and_then(generate),
+        Some("new") => match &args[1..] {
+            [day] => new_day(day),
+            _ => Err(USAGE.to_string()),
+        },
+        #[cfg(feature = "alloc-stats")]
+        Some("alloc") => match &args[1..] {
+            [] => alloc::report(None),
+            [day] => parse_day(day).and_then(|day| alloc::report(Some(day))),
+            _ => Err(USAGE.to_string()),
+        },
+        #[cfg(not(feature = "alloc-stats"))]
+        Some("alloc") => Err(
+            "Allocation tracking is off, run with `cargo run --features alloc-stats -- alloc`"
+                .to_string(),
+        ),
+        Some("verify") => match &args[1..] {
+            [] => verify::verify(false),
+            [flag] if flag == "--record" => verify::verify(true),
+            [flag] if flag == "--reference" => verify::verify_references(),
+            _ => Err(USAGE.to_string()),
+        },
+        _ => Err(USAGE.to_string()),
+    };
+
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_day_only() {
+        assert_eq!(
+            parse_run_args(&args(&["7"])),
+            Ok(RunArgs {
+                day: 7,
+                part: None,
+                input: Source::Default,
+                format: Format::Text,
+            })
+        );
+    }
+
+    #[test]
+    fn parses_part_and_input() {
+        assert_eq!(
+            parse_run_args(&args(&["day01", "--part", "2", "--input", "example.txt"])),
+            Ok(RunArgs {
+                day: 1,
+                part: Some(Part::Two),
+                input: Source::File("example.txt".into()),
+                format: Format::Text,
+            })
+        );
+    }
+
+    #[test]
+    fn parses_example_and_stdin_inputs() {
+        let run_args = parse_run_args(&args(&["1", "--example", "part2"])).unwrap();
+        assert_eq!(run_args.input, Source::Example("part2".to_string()));
+
+        let run_args = parse_run_args(&args(&["1", "--input", "-"])).unwrap();
+        assert_eq!(run_args.input, Source::Stdin);
+    }
+
+    #[test]
+    fn parses_output_format() {
+        let run_args = parse_run_args(&args(&["6", "--format", "json"])).unwrap();
+        assert_eq!(run_args.format, Format::Json);
+        assert!(parse_run_args(&args(&["6", "--format", "yaml"])).is_err());
+    }
+
+    #[test]
+    fn parses_generate_args() {
+        assert_eq!(
+            parse_generate_args(&args(&["day04", "--size", "500", "--seed", "7"])),
+            Ok(GenerateArgs {
+                day: 4,
+                size: 500,
+                seed: 7,
+            })
+        );
+        assert!(parse_generate_args(&args(&["4"])).is_err());
+        assert!(parse_generate_args(&args(&["4", "--size"])).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_part() {
+        assert!(parse_run_args(&args(&["1", "--part", "3"])).is_err());
+    }
+
+    #[test]
+    fn dispatches_to_day_solver() {
+        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+        let day = days::find(1).unwrap();
+        let answers: Vec<_> = (day.run)(input, &Part::ALL)
+            .unwrap()
+            .parts
+            .into_iter()
+            .map(|part_run| part_run.answer)
+            .collect();
+        assert_eq!(answers, ["3", "6"]);
+    }
 }
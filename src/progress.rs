@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+use std::fs;
+use std::time::Duration;
+
+use common::{DEFAULT_VARIANT, Part};
+
+use crate::answers::{ANSWERS_FILE, Answers};
+use crate::days::{self, Day};
+use crate::history::{self, HISTORY_FILE};
+use crate::verify::{self, Status};
+
+pub const README_FILE: &str = "README.md";
+const START_MARKER: &str = "<!-- progress:start -->";
+const END_MARKER: &str = "<!-- progress:end -->";
+
+// One row of the progress table.
+#[derive(Debug, PartialEq)]
+struct Row {
+    day: u8,
+    title: String,
+    stars: usize,
+    medians: [Option<Duration>; 2],
+    learnings: String,
+}
+
+// "# Day 4: Printing Department" -> "Printing Department"
+fn title(day_readme: &str) -> Option<&str> {
+    day_readme
+        .lines()
+        .find_map(|line| line.strip_prefix("# Day "))
+        .and_then(|heading| heading.split_once(": "))
+        .map(|(_, title)| title.trim())
+}
+
+// The Learnings column is written by hand, so it's carried over from the
+// current table by day.
+fn learnings(table: &str) -> HashMap<u8, String> {
+    table
+        .lines()
+        .filter_map(|line| {
+            let cells: Vec<&str> = line.trim().trim_matches('|').split('|').collect();
+            let day = cells.first()?.trim().parse::<u8>().ok()?;
+            Some((day, cells.last()?.trim().to_string()))
+        })
+        .collect()
+}
+
+fn render(rows: &[Row]) -> String {
+    let mut table = String::from(
+        "| Day | Puzzle | Stars | Part 1 | Part 2 | Learnings |\n\
+         |-----|--------|-------|--------|--------|-----------|\n",
+    );
+
+    for row in rows {
+        let [part1, part2] = row
+            .medians
+            .map(|median| median.map_or("-".to_string(), |median| format!("{:.2?}", median)));
+        table.push_str(&format!(
+            "| {:02} | [{}](day{:02}/) | {} | {} | {} | {} |\n",
+            row.day,
+            row.title,
+            row.day,
+            "⭐".repeat(row.stars),
+            part1,
+            part2,
+            row.learnings
+        ));
+    }
+
+    table
+}
+
+// Swaps whatever sits between the progress markers for `table`.
+fn replace_table(readme: &str, table: &str) -> Result<String, String> {
+    let missing = || {
+        format!(
+            "{} needs {} and {} around the progress table",
+            README_FILE, START_MARKER, END_MARKER
+        )
+    };
+    let (before, rest) = readme.split_once(START_MARKER).ok_or_else(missing)?;
+    let (_, after) = rest.split_once(END_MARKER).ok_or_else(missing)?;
+
+    Ok(format!(
+        "{}{}\n{}{}{}",
+        before, START_MARKER, table, END_MARKER, after
+    ))
+}
+
+fn current_table(readme: &str) -> &str {
+    readme
+        .split_once(START_MARKER)
+        .and_then(|(_, rest)| rest.split_once(END_MARKER))
+        .map_or("", |(table, _)| table)
+}
+
+// A star for every part whose answer still matches `answers.txt`; none yet
+// for a day without an input.
+fn stars(day: &Day, recorded: &Answers) -> Result<usize, String> {
+    let Some(input) = day.read_input_if_present()? else {
+        eprintln!("Day {:02}: missing input", day.number);
+        return Ok(0);
+    };
+    let day_run = (day.run)(&input, &Part::ALL).map_err(|err| err.to_string())?;
+
+    Ok(day_run
+        .parts
+        .iter()
+        .filter(|part_run| verify::check(recorded, day, part_run) == Status::Ok)
+        .count())
+}
+
+// Rebuilds the progress table in README.md from the day READMEs, verified
+// answers and the latest default-variant medians in bench_history.csv.
+pub fn update_readme() -> Result<(), String> {
+    let readme = fs::read_to_string(README_FILE)
+        .map_err(|err| format!("Failed to read {}: {}", README_FILE, err))?;
+    let recorded = Answers::load(ANSWERS_FILE)?;
+    let history = history::load(HISTORY_FILE)?;
+    let latest = history::latest(&history);
+    let mut learnings = learnings(current_table(&readme));
+
+    let mut rows = Vec::new();
+    for day in days::DAYS {
+        let path = format!("day{:02}/README.md", day.number);
+        let day_readme =
+            fs::read_to_string(&path).map_err(|err| format!("Failed to read {}: {}", path, err))?;
+
+        rows.push(Row {
+            day: day.number,
+            title: title(&day_readme)
+                .ok_or(format!("{} has no \"# Day N: Title\" heading", path))?
+                .to_string(),
+            stars: stars(day, &recorded)?,
+            medians: Part::ALL.map(|part| {
+                latest
+                    .get(&(day.number, part, DEFAULT_VARIANT))
+                    .map(|record| record.median)
+            }),
+            learnings: learnings.remove(&day.number).unwrap_or_default(),
+        });
+    }
+
+    fs::write(README_FILE, replace_table(&readme, &render(&rows))?)
+        .map_err(|err| format!("Failed to write {}: {}", README_FILE, err))?;
+    println!("Updated the progress table in {}", README_FILE);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const README: &str = "# Title
+
+<!-- progress:start -->
+| Day | Puzzle | Stars | Learnings |
+|-----|--------|-------|-----------|
+| 01 | [Secret Entrance](day01/) | ⭐⭐ | Parsing, `rem_euclid` |
+<!-- progress:end -->
+
+## Running
+";
+
+    #[test]
+    fn reads_title_from_heading() {
+        assert_eq!(
+            title("# Day 4: Printing Department\n\nText"),
+            Some("Printing Department")
+        );
+        assert_eq!(title("# Notes"), None);
+    }
+
+    #[test]
+    fn keeps_learnings_by_day() {
+        let learnings = learnings(current_table(README));
+        assert_eq!(learnings.len(), 1);
+        assert_eq!(learnings[&1], "Parsing, `rem_euclid`");
+    }
+
+    #[test]
+    fn leaves_unwritten_learnings_empty() {
+        let learnings = learnings("| 05 | [Cafeteria](day05/) | ⭐⭐ | - | - |  |\n");
+        assert_eq!(learnings[&5], "");
+        assert!(!learnings.contains_key(&6));
+    }
+
+    #[test]
+    fn replaces_only_the_marked_table() {
+        let rows = [Row {
+            day: 1,
+            title: "Secret Entrance".to_string(),
+            stars: 1,
+            medians: [Some(Duration::from_micros(1500)), None],
+            learnings: "Parsing".to_string(),
+        }];
+        let updated = replace_table(README, &render(&rows)).unwrap();
+
+        assert!(updated.starts_with("# Title\n\n<!-- progress:start -->\n| Day | Puzzle |"));
+        assert!(updated.contains("| 01 | [Secret Entrance](day01/) | ⭐ | 1.50ms | - | Parsing |\n<!-- progress:end -->\n\n## Running\n"));
+        assert!(replace_table("# No markers", "").is_err());
+    }
+}
@@ -0,0 +1,222 @@
+use std::fs;
+use std::path::Path;
+
+// (path inside dayNN/, template) with __DAY__ and __NN__ placeholders
+const TEMPLATES: [(&str, &str); 5] = [
+    (
+        "Cargo.toml",
+        include_str!("../templates/day/Cargo.toml.tmpl"),
+    ),
+    ("README.md", include_str!("../templates/day/README.md.tmpl")),
+    (
+        "examples.txt",
+        include_str!("../templates/day/examples.txt.tmpl"),
+    ),
+    (
+        "src/lib.rs",
+        include_str!("../templates/day/src/lib.rs.tmpl"),
+    ),
+    (
+        "src/main.rs",
+        include_str!("../templates/day/src/main.rs.tmpl"),
+    ),
+];
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("__NN__", &format!("{:02}", day))
+        .replace("__DAY__", &day.to_string())
+}
+
+// Inserts `line` into the block of lines between `start` and the first line
+// matching `is_end`, keeping the lines accepted by `is_entry` sorted.
+fn insert_sorted(
+    content: &str,
+    start: &str,
+    is_end: impl Fn(&str) -> bool,
+    is_entry: impl Fn(&str) -> bool,
+    line: &str,
+) -> Result<String, String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let start_idx = lines
+        .iter()
+        .position(|l| l.trim() == start)
+        .ok_or(format!("Could not find `{}`", start))?;
+    let end_idx = lines[start_idx + 1..]
+        .iter()
+        .position(|l| is_end(l))
+        .map_or(lines.len(), |idx| start_idx + 1 + idx);
+
+    let block = &lines[start_idx + 1..end_idx];
+    if block.contains(&line) {
+        return Err(format!("`{}` is already there", line.trim()));
+    }
+
+    let insert_at = block
+        .iter()
+        .rposition(|l| is_entry(l) && *l < line)
+        .map_or(start_idx + 1, |idx| start_idx + 2 + idx);
+    lines.insert(insert_at, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn add_member(cargo_toml: &str, day: u8) -> Result<String, String> {
+    let name = format!("\"day{:02}\"", day);
+    let line = cargo_toml
+        .lines()
+        .find(|line| line.trim_start().starts_with("members = ["))
+        .ok_or("Could not find the workspace members")?;
+
+    let list = line
+        .trim()
+        .trim_start_matches("members = [")
+        .trim_end_matches(']');
+    let mut members: Vec<&str> = list
+        .split(',')
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+        .collect();
+    if members.contains(&name.as_str()) {
+        return Err(format!("{} is already a workspace member", name));
+    }
+    members.push(&name);
+    members.sort();
+
+    Ok(cargo_toml.replacen(line, &format!("members = [{}]", members.join(", ")), 1))
+}
+
+fn add_dependency(cargo_toml: &str, day: u8) -> Result<String, String> {
+    let line = format!("day{:02} = {{ path = \"day{:02}\" }}", day, day);
+    insert_sorted(
+        cargo_toml,
+        "[dependencies]",
+        |l| l.trim().is_empty() || l.starts_with('['),
+        |l| l.starts_with("day"),
+        &line,
+    )
+}
+
+fn register_day(days_rs: &str, day: u8) -> Result<String, String> {
+    let line = format!("    day::<day{:02}::Day{:02}>(),", day, day);
+    insert_sorted(
+        days_rs,
+        "pub const DAYS: &[Day] = &[",
+        |l| l.trim() == "];",
+        |l| l.trim_start().starts_with("day::<"),
+        &line,
+    )
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("Failed to read {}: {}", path.display(), err))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("Failed to create {}: {}", parent.display(), err))?;
+    }
+    fs::write(path, content).map_err(|err| format!("Failed to write {}: {}", path.display(), err))
+}
+
+// Creates dayNN/ from the templates, adds it to the workspace and registers
+// it with the runner. `root` is the workspace root.
+pub fn new_day(root: &Path, day: u8) -> Result<(), String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Invalid day: {}", day));
+    }
+
+    let day_dir = root.join(format!("day{:02}", day));
+    if day_dir.exists() {
+        return Err(format!("{} already exists", day_dir.display()));
+    }
+
+    // prepare the shared file edits first so a failure leaves no half-made crate
+    let cargo_toml = root.join("Cargo.toml");
+    let days_rs = root.join("src/days.rs");
+    let new_cargo_toml = add_dependency(&add_member(&read(&cargo_toml)?, day)?, day)?;
+    let new_days_rs = register_day(&read(&days_rs)?, day)?;
+
+    for (path, template) in TEMPLATES {
+        write(&day_dir.join(path), &render(template, day))?;
+    }
+    write(&cargo_toml, &new_cargo_toml)?;
+    write(&days_rs, &new_days_rs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CARGO_TOML: &str = r#"[package]
+name = "aoc2025"
+
+[workspace]
+members = ["common", "day01", "day03"]
+
+[dependencies]
+common = { path = "common" }
+day01 = { path = "day01" }
+day03 = { path = "day03" }
+"#;
+
+    const DAYS_RS: &str = "pub const DAYS: &[Day] = &[
+    day::<day01::Day01>(),
+    day::<day03::Day03>(),
+];
+";
+
+    #[test]
+    fn adds_workspace_member_in_order() {
+        let updated = add_member(CARGO_TOML, 2).unwrap();
+        assert!(updated.contains(r#"members = ["common", "day01", "day02", "day03"]"#));
+        assert!(add_member(CARGO_TOML, 1).is_err());
+    }
+
+    #[test]
+    fn adds_runner_dependency_in_order() {
+        let updated = add_dependency(CARGO_TOML, 2).unwrap();
+        assert!(updated.contains(
+            "day01 = { path = \"day01\" }\nday02 = { path = \"day02\" }\nday03 = { path = \"day03\" }\n"
+        ));
+
+        let updated = add_dependency(CARGO_TOML, 8).unwrap();
+        assert!(updated.ends_with("day03 = { path = \"day03\" }\nday08 = { path = \"day08\" }\n"));
+    }
+
+    #[test]
+    fn registers_day_in_order() {
+        let updated = register_day(DAYS_RS, 2).unwrap();
+        assert_eq!(
+            updated,
+            "pub const DAYS: &[Day] = &[
+    day::<day01::Day01>(),
+    day::<day02::Day02>(),
+    day::<day03::Day03>(),
+];
+"
+        );
+        assert!(register_day(DAYS_RS, 3).is_err());
+    }
+
+    #[test]
+    fn scaffolds_a_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc2025_scaffold_{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("Cargo.toml"), CARGO_TOML).unwrap();
+        fs::write(root.join("src/days.rs"), DAYS_RS).unwrap();
+
+        new_day(&root, 8).unwrap();
+        let lib = fs::read_to_string(root.join("day08/src/lib.rs")).unwrap();
+        let readme = fs::read_to_string(root.join("day08/README.md")).unwrap();
+        let days_rs = fs::read_to_string(root.join("src/days.rs")).unwrap();
+        let second_attempt = new_day(&root, 8);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(lib.contains("impl Solution for Day08 {\n    const DAY: u8 = 8;"));
+        assert!(readme.starts_with("# Day 8: "));
+        assert!(days_rs.contains("day::<day08::Day08>(),"));
+        assert!(second_attempt.is_err());
+    }
+}
@@ -0,0 +1,429 @@
+use std::fmt;
+use std::fs;
+use std::io::Write;
+use std::time::Duration;
+
+use common::Part;
+
+use crate::config::Config;
+use crate::http;
+
+pub const SUBMISSIONS_FILE: &str = "submissions.csv";
+const HEADER: &str = "timestamp,day,part,answer,outcome,retry_at";
+
+// What the site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    // submitted during a cooldown, the answer wasn't checked
+    TooSoon,
+    // the part was already solved on the site
+    AlreadySolved,
+}
+
+const OUTCOMES: [(Outcome, &str); 6] = [
+    (Outcome::Correct, "correct"),
+    (Outcome::TooHigh, "too-high"),
+    (Outcome::TooLow, "too-low"),
+    (Outcome::Wrong, "wrong"),
+    (Outcome::TooSoon, "too-soon"),
+    (Outcome::AlreadySolved, "already-solved"),
+];
+
+impl Outcome {
+    fn from_name(name: &str) -> Option<Outcome> {
+        OUTCOMES
+            .iter()
+            .find(|(_, n)| *n == name)
+            .map(|(outcome, _)| *outcome)
+    }
+
+    // Whether the answer itself was judged wrong.
+    fn is_wrong(self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = OUTCOMES.iter().find(|(o, _)| o == self).map(|(_, n)| *n);
+        write!(f, "{}", name.unwrap_or("?"))
+    }
+}
+
+// Finds the verdict in the response page.
+fn parse_outcome(body: &str) -> Result<Outcome, String> {
+    let text = body.to_lowercase();
+    let checks = [
+        ("that's the right answer", Outcome::Correct),
+        (
+            "you don't seem to be solving the right level",
+            Outcome::AlreadySolved,
+        ),
+        ("you gave an answer too recently", Outcome::TooSoon),
+        ("your answer is too high", Outcome::TooHigh),
+        ("your answer is too low", Outcome::TooLow),
+        ("that's not the right answer", Outcome::Wrong),
+    ];
+
+    checks
+        .iter()
+        .find(|(phrase, _)| text.contains(phrase))
+        .map(|(_, outcome)| *outcome)
+        .ok_or(format!("Unrecognised response: {}", body.trim()))
+}
+
+// "you have 1m 5s left to wait" or "please wait 5 minutes before trying again"
+fn parse_cooldown(body: &str) -> Option<Duration> {
+    let text = body.to_lowercase();
+
+    // "you have 1m 5s left to wait": the duration is the tokens just before
+    if let Some((before, _)) = text.split_once(" left to wait") {
+        let amounts: Vec<u64> = before
+            .split_whitespace()
+            .rev()
+            .map_while(|amount| {
+                let (number, unit) = amount.split_at(amount.len().checked_sub(1)?);
+                let number: u64 = number.parse().ok()?;
+                match unit {
+                    "h" => Some(number * 3600),
+                    "m" => Some(number * 60),
+                    "s" => Some(number),
+                    _ => None,
+                }
+            })
+            .collect();
+        if !amounts.is_empty() {
+            return Some(Duration::from_secs(amounts.iter().sum()));
+        }
+    }
+
+    let (_, rest) = text.split_once("please wait ")?;
+    let mut words = rest.split_whitespace();
+    let minutes = match words.next()? {
+        "one" => 1,
+        number => number.parse().ok()?,
+    };
+    words
+        .next()?
+        .starts_with("minute")
+        .then(|| Duration::from_secs(minutes * 60))
+}
+
+// One submission, as stored in `submissions.csv`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub timestamp: u64,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+    // unix time before which nothing should be submitted, 0 for none
+    pub retry_at: u64,
+}
+
+impl Record {
+    fn parse(line: &str) -> Option<Record> {
+        let fields: Vec<&str> = line.split(',').collect();
+        let [timestamp, day, part, answer, outcome, retry_at] = fields[..] else {
+            return None;
+        };
+
+        Some(Record {
+            timestamp: timestamp.parse().ok()?,
+            day: day.parse().ok()?,
+            part: Part::from_number(part.parse().ok()?)?,
+            answer: answer.to_string(),
+            outcome: Outcome::from_name(outcome)?,
+            retry_at: retry_at.parse().ok()?,
+        })
+    }
+
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{}",
+            self.timestamp,
+            self.day,
+            self.part.number(),
+            self.answer,
+            self.outcome,
+            self.retry_at
+        )
+    }
+}
+
+pub fn load(path: &str) -> Result<Vec<Record>, String> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(format!("Failed to read {}: {}", path, err)),
+    };
+
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && *line != HEADER)
+        .map(|(idx, line)| {
+            Record::parse(line).ok_or(format!(
+                "{}:{}: invalid submission: {}",
+                path,
+                idx + 1,
+                line
+            ))
+        })
+        .collect()
+}
+
+fn append(path: &str, record: &Record) -> Result<(), String> {
+    let is_new = fs::metadata(path).is_err();
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|err| format!("Failed to open {}: {}", path, err))?;
+
+    let mut content = String::new();
+    if is_new {
+        content.push_str(HEADER);
+        content.push('\n');
+    }
+    content.push_str(&record.to_csv());
+    content.push('\n');
+
+    file.write_all(content.as_bytes())
+        .map_err(|err| format!("Failed to write {}: {}", path, err))
+}
+
+// Refuses submissions the log already knows the answer to: a solved part, a
+// repeated wrong answer, one outside the too-high/too-low bounds, or anything
+// during a cooldown.
+fn check(records: &[Record], day: u8, part: Part, answer: &str, now: u64) -> Result<(), String> {
+    if let Some(wait) = records
+        .iter()
+        .map(|r| r.retry_at)
+        .max()
+        .filter(|&at| at > now)
+    {
+        return Err(format!(
+            "Still cooling down, wait {}s before submitting again",
+            wait - now
+        ));
+    }
+
+    let number = answer.parse::<i128>().ok();
+    for record in records.iter().filter(|r| r.day == day && r.part == part) {
+        let previous = record.answer.parse::<i128>().ok();
+        let refuse = |reason: &str| {
+            Err(format!(
+                "Not submitting {}: {} was {}",
+                answer, record.answer, reason
+            ))
+        };
+
+        match record.outcome {
+            Outcome::Correct | Outcome::AlreadySolved => {
+                return refuse("already accepted");
+            }
+            outcome if outcome.is_wrong() && record.answer == answer => {
+                return refuse(&format!("already rejected as {}", outcome));
+            }
+            Outcome::TooHigh if number.zip(previous).is_some_and(|(n, p)| n >= p) => {
+                return refuse("too high");
+            }
+            Outcome::TooLow if number.zip(previous).is_some_and(|(n, p)| n <= p) => {
+                return refuse("too low");
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+// Posts `answer` unless the log in `path` rules it out, then records what the
+// site said (and any cooldown it asked for).
+pub fn submit(
+    path: &str,
+    config: &Config,
+    day: u8,
+    part: Part,
+    answer: &str,
+    now: u64,
+) -> Result<Record, String> {
+    check(&load(path)?, day, part, answer, now)?;
+
+    let url = format!("{}/answer", config.day_url(day));
+    let form = format!("level={}&answer={}", part.number(), encode(answer));
+    let response = http::post(&url, config.session()?, &form)?;
+    if response.status != 200 {
+        return Err(format!(
+            "Failed to submit to {}: HTTP {}",
+            url, response.status
+        ));
+    }
+
+    let record = Record {
+        timestamp: now,
+        day,
+        part,
+        answer: answer.to_string(),
+        outcome: parse_outcome(&response.body)?,
+        retry_at: parse_cooldown(&response.body).map_or(0, |wait| now + wait.as_secs()),
+    };
+    append(path, &record)?;
+
+    Ok(record)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::stub;
+
+    fn record(answer: &str, outcome: Outcome, retry_at: u64) -> Record {
+        Record {
+            timestamp: 100,
+            day: 1,
+            part: Part::One,
+            answer: answer.to_string(),
+            outcome,
+            retry_at,
+        }
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        let page = |text: &str| format!("<main><article><p>{}</p></article></main>", text);
+        let cases = [
+            (
+                "That's the right answer! You are one gold star closer.",
+                Outcome::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Outcome::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Outcome::TooLow,
+            ),
+            ("That's not the right answer.", Outcome::Wrong),
+            ("You gave an answer too recently.", Outcome::TooSoon),
+            (
+                "You don't seem to be solving the right level.",
+                Outcome::AlreadySolved,
+            ),
+        ];
+
+        for (text, outcome) in cases {
+            assert_eq!(parse_outcome(&page(text)), Ok(outcome));
+        }
+        assert!(parse_outcome("<p>Something else</p>").is_err());
+    }
+
+    #[test]
+    fn parses_cooldowns() {
+        let secs = Duration::from_secs;
+        assert_eq!(
+            parse_cooldown("You have 1m 5s left to wait."),
+            Some(secs(65))
+        );
+        assert_eq!(parse_cooldown("You have 35s left to wait."), Some(secs(35)));
+        assert_eq!(
+            parse_cooldown("Please wait one minute before trying again."),
+            Some(secs(60))
+        );
+        assert_eq!(
+            parse_cooldown("please wait 5 minutes before trying again"),
+            Some(secs(300))
+        );
+        assert_eq!(
+            parse_cooldown(
+                "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 36s left to wait. <a href=\"/2025/day/1\">[Return to Day 1]</a></p></article>"
+            ),
+            Some(secs(36))
+        );
+        assert_eq!(
+            parse_cooldown(
+                "<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2025/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again. <a href=\"/2025/day/1\">[Return to Day 1]</a></p></article>"
+            ),
+            Some(secs(300))
+        );
+        assert_eq!(parse_cooldown("That's the right answer!"), None);
+    }
+
+    #[test]
+    fn refuses_known_answers_and_cooldowns() {
+        let records = [
+            record("500", Outcome::TooHigh, 0),
+            record("100", Outcome::TooLow, 0),
+            record("abc", Outcome::Wrong, 0),
+        ];
+
+        assert!(check(&records, 1, Part::One, "300", 200).is_ok());
+        assert!(check(&records, 1, Part::One, "500", 200).is_err());
+        assert!(check(&records, 1, Part::One, "600", 200).is_err());
+        assert!(check(&records, 1, Part::One, "100", 200).is_err());
+        assert!(check(&records, 1, Part::One, "abc", 200).is_err());
+        assert!(check(&records, 1, Part::Two, "500", 200).is_ok());
+
+        let cooling = [record("300", Outcome::Wrong, 260)];
+        assert!(check(&cooling, 2, Part::One, "1", 200).is_err());
+        assert!(check(&cooling, 2, Part::One, "1", 260).is_ok());
+
+        let solved = [record("300", Outcome::Correct, 0)];
+        assert!(check(&solved, 1, Part::One, "300", 200).is_err());
+    }
+
+    #[test]
+    fn submits_and_records_against_mock_server() {
+        let path = std::env::temp_dir().join(format!("aoc2025_submit_{}.csv", std::process::id()));
+        let path = path.to_str().unwrap();
+        let (url, server) = stub::serve_once(
+            "200 OK",
+            "<p>That's not the right answer; your answer is too low. Please wait one minute before trying again.</p>",
+        );
+        let config = Config {
+            session: Some("abc123".to_string()),
+            base_url: url,
+        };
+
+        let first = submit(path, &config, 1, Part::Two, "42", 1000);
+        let request = server.join().unwrap();
+        // the cooldown is recorded, so this never reaches the (closed) server
+        let second = submit(path, &config, 1, Part::Two, "43", 1010);
+        let records = load(path);
+        fs::remove_file(path).unwrap();
+
+        assert_eq!(
+            first,
+            Ok(Record {
+                timestamp: 1000,
+                day: 1,
+                part: Part::Two,
+                answer: "42".to_string(),
+                outcome: Outcome::TooLow,
+                retry_at: 1060,
+            })
+        );
+        assert!(request.starts_with("POST /day/1/answer "));
+        assert!(request.ends_with("level=2&answer=42"));
+        assert!(second.unwrap_err().contains("wait 50s"));
+        assert_eq!(records.map(|records| records.len()), Ok(1));
+    }
+}
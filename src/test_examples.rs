@@ -0,0 +1,179 @@
+use std::fs;
+use std::io;
+
+use common::Part;
+use common::input::{self, Source};
+
+use crate::days::{self, Day};
+use crate::table::Table;
+
+const ANSWERS_SUFFIX: &str = "_example_answers.txt";
+
+#[derive(Debug, PartialEq)]
+enum Status {
+    Pass,
+    Fail,
+    // no <name>_example_answers.txt next to the input
+    Unannotated,
+}
+
+// `<part> <answer>` lines of a `<name>_example_answers.txt` sidecar.
+fn parse_expected(name: &str, content: &str) -> Result<Vec<(Part, String)>, String> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(idx, line)| {
+            let invalid = || {
+                format!(
+                    "{}{}:{}: invalid answer line: {}",
+                    name,
+                    ANSWERS_SUFFIX,
+                    idx + 1,
+                    line
+                )
+            };
+            let (part, answer) = line.split_once(char::is_whitespace).ok_or_else(invalid)?;
+            let part = part
+                .parse::<u8>()
+                .ok()
+                .and_then(Part::from_number)
+                .ok_or_else(invalid)?;
+            Ok((part, answer.trim().to_string()))
+        })
+        .collect()
+}
+
+// (part, expected, actual, status) for every expected answer. A solver error
+// fails the example instead of stopping the whole run.
+fn check(
+    day: &Day,
+    input: &str,
+    expected: &[(Part, String)],
+) -> Vec<(Part, String, String, Status)> {
+    let parts: Vec<Part> = expected.iter().map(|(part, _)| *part).collect();
+    let actual = (day.run)(input, &parts).map(|day_run| day_run.parts);
+
+    expected
+        .iter()
+        .enumerate()
+        .map(|(idx, (part, answer))| {
+            let actual = match &actual {
+                Ok(part_runs) => part_runs[idx].answer.clone(),
+                Err(err) => format!("error: {}", err.message),
+            };
+            let status = if actual == *answer {
+                Status::Pass
+            } else {
+                Status::Fail
+            };
+            (*part, answer.clone(), actual, status)
+        })
+        .collect()
+}
+
+// Runs every dayNN/<name>_example_input.txt against the answers in its
+// <name>_example_answers.txt and reports each one.
+pub fn test_examples(day: Option<u8>) -> Result<(), String> {
+    let selected: Vec<&Day> = match day {
+        Some(number) => {
+            vec![days::find(number).ok_or(format!("Day {} is not solved yet", number))?]
+        }
+        None => days::DAYS.iter().collect(),
+    };
+
+    let mut table = Table::new(&["Day", "Example", "Part", "Expected", "Actual", "Status"]);
+    let mut failures = 0;
+
+    for day in selected {
+        let dir = input::day_dir(day.number);
+        let names = input::example_names(&dir)
+            .map_err(|err| format!("Failed to list {}: {}", dir.display(), err))?;
+
+        for name in names {
+            let example = input::load(day.number, &Source::Example(name.clone()))
+                .map_err(|err| err.to_string())?;
+            let answers_path = dir.join(format!("{}{}", name, ANSWERS_SUFFIX));
+            let answers = match fs::read_to_string(&answers_path) {
+                Ok(answers) => answers,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    table.add_row(vec![
+                        format!("{:02}", day.number),
+                        name,
+                        "-".to_string(),
+                        "-".to_string(),
+                        "-".to_string(),
+                        format!("{:?}", Status::Unannotated).to_lowercase(),
+                    ]);
+                    continue;
+                }
+                Err(err) => {
+                    return Err(format!(
+                        "Failed to read {}: {}",
+                        answers_path.display(),
+                        err
+                    ));
+                }
+            };
+
+            for (part, expected, actual, status) in
+                check(day, &example, &parse_expected(&name, &answers)?)
+            {
+                if status == Status::Fail {
+                    failures += 1;
+                }
+                table.add_row(vec![
+                    format!("{:02}", day.number),
+                    name.clone(),
+                    part.number().to_string(),
+                    expected,
+                    actual,
+                    format!("{:?}", status).to_lowercase(),
+                ]);
+            }
+        }
+    }
+
+    print!("{}", table);
+
+    if failures > 0 {
+        return Err(format!("{} example answer(s) failed", failures));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_expected_answers() {
+        assert_eq!(
+            parse_expected("part1", "# part answer\n1 3\n2 6\n"),
+            Ok(vec![
+                (Part::One, "3".to_string()),
+                (Part::Two, "6".to_string())
+            ])
+        );
+        assert!(parse_expected("part1", "3 1").is_err());
+    }
+
+    #[test]
+    fn checks_each_expected_part() {
+        let day = days::find(1).unwrap();
+        let expected = [(Part::Two, "10".to_string()), (Part::One, "1".to_string())];
+        let results = check(day, "R1000", &expected);
+
+        assert_eq!(
+            results[0],
+            (Part::Two, "10".to_string(), "10".to_string(), Status::Pass)
+        );
+        assert_eq!(
+            results[1],
+            (Part::One, "1".to_string(), "0".to_string(), Status::Fail)
+        );
+        assert_eq!(check(day, "X1", &expected)[0].3, Status::Fail);
+    }
+}
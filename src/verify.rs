@@ -0,0 +1,185 @@
+use common::{Part, PartRun};
+
+use crate::answers::{ANSWERS_FILE, Answers};
+use crate::days::{self, Day};
+use crate::table::Table;
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Ok,
+    Changed,
+    Unrecorded,
+}
+
+pub fn check(recorded: &Answers, day: &Day, part_run: &PartRun) -> Status {
+    match recorded.get(day.number, part_run.part) {
+        Some(expected) if expected == part_run.answer => Status::Ok,
+        Some(_) => Status::Changed,
+        None => Status::Unrecorded,
+    }
+}
+
+// Days without an input.txt are reported instead of stopping the whole run.
+fn missing_input_row(day: &Day) -> Vec<String> {
+    vec![
+        format!("{:02}", day.number),
+        "-".to_string(),
+        "-".to_string(),
+        "-".to_string(),
+        "missing input".to_string(),
+    ]
+}
+
+// Re-runs every day against its real input and compares with `answers.txt`.
+// With `record` the current answers are written back instead of failing.
+pub fn verify(record: bool) -> Result<(), String> {
+    let mut recorded = Answers::load(ANSWERS_FILE)?;
+    let mut table = Table::new(&["Day", "Part", "Expected", "Actual", "Status"]);
+    let mut diff = Vec::new();
+    let mut updates = Vec::new();
+
+    for day in days::DAYS {
+        let Some(input) = day.read_input_if_present()? else {
+            table.add_row(missing_input_row(day));
+            continue;
+        };
+
+        let day_run = (day.run)(&input, &Part::ALL).map_err(|err| err.to_string())?;
+
+        for part_run in day_run.parts {
+            let expected = recorded.get(day.number, part_run.part).unwrap_or("-");
+            let status = check(&recorded, day, &part_run);
+
+            if status == Status::Changed {
+                diff.push(format!(
+                    "-{:02} {} {}",
+                    day.number,
+                    part_run.part.number(),
+                    expected
+                ));
+                diff.push(format!(
+                    "+{:02} {} {}",
+                    day.number,
+                    part_run.part.number(),
+                    part_run.answer
+                ));
+            }
+
+            table.add_row(vec![
+                format!("{:02}", day.number),
+                part_run.part.number().to_string(),
+                expected.to_string(),
+                part_run.answer.clone(),
+                format!("{:?}", status).to_lowercase(),
+            ]);
+            updates.push((day.number, part_run.part, part_run.answer));
+        }
+    }
+
+    print!("{}", table);
+
+    if record {
+        for (day, part, answer) in updates {
+            recorded.insert(day, part, answer);
+        }
+        recorded.save(ANSWERS_FILE)?;
+        println!("Recorded answers in {}", ANSWERS_FILE);
+        return Ok(());
+    }
+
+    if diff.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Answers changed:\n--- {}\n+++ actual\n{}",
+            ANSWERS_FILE,
+            diff.join("\n")
+        ))
+    }
+}
+
+// Runs the brute-force references (built with `--features reference`) on
+// the real inputs and compares them with what the solvers answer.
+pub fn verify_references() -> Result<(), String> {
+    let mut table = Table::new(&["Day", "Part", "Solver", "Reference", "Status"]);
+    let mut failures = 0;
+    let mut checked_days = 0;
+
+    for day in days::DAYS {
+        let Some(input) = day.read_input_if_present()? else {
+            table.add_row(missing_input_row(day));
+            continue;
+        };
+        let references = (day.references)(&input);
+        if references.is_empty() {
+            continue;
+        }
+        checked_days += 1;
+
+        let day_run = (day.run)(&input, &Part::ALL).map_err(|err| err.to_string())?;
+        for (part, reference) in references {
+            let solver = day_run
+                .parts
+                .iter()
+                .find(|part_run| part_run.part == part)
+                .map_or("-", |part_run| part_run.answer.as_str());
+            // a reference that can't read the input is broken, not skipped
+            let (answer, status) = match reference {
+                Ok(None) => ("-".to_string(), "skipped (too big)".to_string()),
+                Ok(Some(answer)) if answer == solver => (answer, "ok".to_string()),
+                Ok(Some(answer)) => {
+                    failures += 1;
+                    (answer, "MISMATCH".to_string())
+                }
+                Err(err) => {
+                    failures += 1;
+                    ("-".to_string(), format!("ERROR: {}", err))
+                }
+            };
+
+            table.add_row(vec![
+                format!("{:02}", day.number),
+                part.number().to_string(),
+                solver.to_string(),
+                answer,
+                status,
+            ]);
+        }
+    }
+
+    if checked_days == 0 {
+        return Err(
+            "No reference solvers built, run with `cargo run --release --features reference -- verify --reference`"
+                .to_string(),
+        );
+    }
+    print!("{}", table);
+
+    match failures {
+        0 => Ok(()),
+        count => Err(format!(
+            "{} answer(s) differ from or failed in the reference",
+            count
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_against_recorded_answers() {
+        let day = days::find(1).unwrap();
+        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+        let run = (day.run)(input, &Part::ALL).unwrap();
+        let recorded = Answers::parse("01 1 3\n01 2 7").unwrap();
+
+        assert_eq!(check(&recorded, day, &run.parts[0]), Status::Ok);
+        assert_eq!(check(&recorded, day, &run.parts[1]), Status::Changed);
+        assert_eq!(
+            check(&Answers::default(), day, &run.parts[0]),
+            Status::Unrecorded
+        );
+    }
+}
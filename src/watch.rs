@@ -0,0 +1,182 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use common::input;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// Modification time of every watched file.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+// An editor's atomic save (write a temp file, rename it over the original)
+// can remove files between listing a directory and reading an entry; those
+// are skipped and picked up on the next poll.
+fn skip_missing<T>(result: io::Result<T>) -> io::Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+fn add_files(
+    dir: &Path,
+    recursive: bool,
+    filter: &dyn Fn(&Path) -> bool,
+    snapshot: &mut Snapshot,
+) -> io::Result<()> {
+    let Some(entries) = skip_missing(fs::read_dir(dir))? else {
+        return Ok(());
+    };
+    for entry in entries {
+        let Some(entry) = skip_missing(entry)? else {
+            continue;
+        };
+        let path = entry.path();
+        let Some(meta) = skip_missing(entry.metadata())? else {
+            continue;
+        };
+
+        if meta.is_dir() {
+            if recursive {
+                add_files(&path, recursive, filter, snapshot)?;
+            }
+        } else if filter(&path) {
+            snapshot.insert(path, meta.modified()?);
+        }
+    }
+    Ok(())
+}
+
+// Everything under dayNN/src plus dayNN/*.txt (inputs, examples, answers).
+fn snapshot(day_dir: &Path) -> io::Result<Snapshot> {
+    let mut snapshot = Snapshot::new();
+    let src = day_dir.join("src");
+    if src.is_dir() {
+        add_files(&src, true, &|_| true, &mut snapshot)?;
+    }
+    let is_txt = |path: &Path| path.extension().is_some_and(|ext| ext == "txt");
+    add_files(day_dir, false, &is_txt, &mut snapshot)?;
+    Ok(snapshot)
+}
+
+// Files that were added, removed or modified between two snapshots.
+fn changes(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    let mut changed: Vec<PathBuf> = after
+        .iter()
+        .filter(|(path, modified)| before.get(*path) != Some(modified))
+        .map(|(path, _)| path.clone())
+        .collect();
+    changed.extend(
+        before
+            .keys()
+            .filter(|path| !after.contains_key(*path))
+            .cloned(),
+    );
+    changed.sort();
+    changed
+}
+
+// Runs one step of the loop with its output shown, then a pass/fail line.
+fn step(name: &str, args: &[&str]) -> bool {
+    println!("== {}", name);
+    let passed = Command::new("cargo")
+        .args(args)
+        .status()
+        .is_ok_and(|status| status.success());
+    println!("== {}: {}", name, if passed { "pass" } else { "FAIL" });
+    passed
+}
+
+fn rerun(day: u8) {
+    let package = format!("day{:02}", day);
+    let day = day.to_string();
+
+    // a failing build or example makes the real answers meaningless
+    let _ = step("tests", &["test", "-q", "-p", &package])
+        && step(
+            "example files",
+            &["run", "-q", "--release", "--", "test-examples", &day],
+        )
+        && step("real input", &["run", "-q", "--release", "--", "run", &day]);
+}
+
+// Polls dayNN/src and dayNN/*.txt and, whenever something changes, rebuilds
+// and re-runs the day's tests and examples and then the real input.
+pub fn watch(day: u8) -> Result<(), String> {
+    let day_dir = input::day_dir(day);
+    let read = || {
+        snapshot(&day_dir).map_err(|err| format!("Failed to watch {}: {}", day_dir.display(), err))
+    };
+
+    let mut last = read()?;
+    println!("Watching {} (Ctrl-C to stop)", day_dir.display());
+    rerun(day);
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let current = read()?;
+        let changed = changes(&last, &current);
+        if changed.is_empty() {
+            continue;
+        }
+
+        for path in &changed {
+            println!("Changed: {}", path.display());
+        }
+        rerun(day);
+        // pick up anything the run itself touched, like a fetched input
+        last = read()?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_files_that_disappear() {
+        let mut snapshot = Snapshot::new();
+        let missing = std::env::temp_dir().join("aoc2025_watch_missing_dir");
+        assert!(add_files(&missing, true, &|_| true, &mut snapshot).is_ok());
+        assert!(snapshot.is_empty());
+    }
+
+    #[test]
+    fn detects_added_modified_and_removed_files() {
+        let dir = std::env::temp_dir().join(format!("aoc2025_watch_{}", std::process::id()));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "").unwrap();
+        fs::write(dir.join("input.txt"), "1").unwrap();
+        fs::write(dir.join("Cargo.toml"), "").unwrap();
+        let before = snapshot(&dir).unwrap();
+
+        let lib = fs::File::options()
+            .write(true)
+            .open(dir.join("src/lib.rs"))
+            .unwrap();
+        lib.set_modified(SystemTime::now() + Duration::from_secs(5))
+            .unwrap();
+        fs::write(dir.join("part1_example_input.txt"), "").unwrap();
+        fs::remove_file(dir.join("input.txt")).unwrap();
+        fs::write(dir.join("Cargo.toml"), "[package]").unwrap();
+        let after = snapshot(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(before.len(), 2);
+        assert_eq!(
+            changes(&before, &after),
+            [
+                dir.join("input.txt"),
+                dir.join("part1_example_input.txt"),
+                dir.join("src/lib.rs"),
+            ]
+        );
+        assert!(changes(&after, &after).is_empty());
+    }
+}